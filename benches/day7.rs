@@ -0,0 +1,48 @@
+use aoc23::day7::{self, Ruleset};
+use aoc23::Input;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+/// A million random hands with bids, generated deterministically.
+fn generated_input() -> Input {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut text = String::new();
+    for _ in 0..1_000_000 {
+        for _ in 0..5 {
+            text.push(CARDS[(next() % CARDS.len() as u64) as usize] as char);
+        }
+        text.push_str(&format!(" {}\n", next() % 1000 + 1));
+    }
+    Input::new("generated.txt", &text)
+}
+
+fn total_winnings(c: &mut Criterion) {
+    let input = generated_input();
+    let ruleset = Ruleset::jokers();
+    assert_eq!(
+        day7::total_winnings(&input, &ruleset).unwrap(),
+        day7::total_winnings_packed(&input, &ruleset).unwrap()
+    );
+
+    let mut group = c.benchmark_group("day7 million hands");
+    group.sample_size(10);
+    group.bench_function("hands", |b| {
+        b.iter(|| day7::total_winnings(black_box(&input), &ruleset))
+    });
+    group.bench_function("packed keys", |b| {
+        b.iter(|| day7::total_winnings_packed(black_box(&input), &ruleset))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, total_winnings);
+criterion_main!(benches);
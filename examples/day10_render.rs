@@ -0,0 +1,24 @@
+use anyhow::Result;
+use aoc23::{day10, Input, Solution};
+use std::fs;
+
+/// Renders the day 10 loop of the given input (defaults to `input/day10.txt`) and optionally
+/// writes it as SVG:
+///
+/// ```text
+/// cargo run --example day10_render -- input/day10_example4.txt loop.svg
+/// ```
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let input_path = args.next().unwrap_or_else(|| "input/day10.txt".to_owned());
+    let puzzle = day10::Puzzle::parse(&Input::read(&input_path)?)?;
+
+    print!("{}", puzzle.render_text());
+    println!("Inside: {}", puzzle.part2()?);
+
+    if let Some(svg_path) = args.next() {
+        fs::write(svg_path, puzzle.render_svg())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use aoc23::{day7, Input, Solution};
+
+/// Prints the part II ranking of the day 7 hands of the given input (defaults to
+/// `input/day7.txt`) as CSV, followed by the number of hands per type and the hands which
+/// jokers upgraded:
+///
+/// ```text
+/// cargo run --example day7_report -- input/day7_example.txt > ranking.csv
+/// ```
+fn main() -> Result<()> {
+    let input_path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "input/day7.txt".to_owned());
+    let puzzle = day7::Puzzle::parse(&Input::read(&input_path)?)?;
+    let report = puzzle.jokers_report();
+
+    print!("{}", report.to_csv());
+
+    for (hand_type, count) in report.hand_type_counts() {
+        eprintln!("{hand_type}: {count}");
+    }
+    for upgrade in puzzle.joker_upgrades() {
+        eprintln!(
+            "line {}: {} upgraded from {} to {}",
+            upgrade.line, upgrade.cards, upgrade.from, upgrade.to
+        );
+    }
+
+    Ok(())
+}
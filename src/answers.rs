@@ -0,0 +1,141 @@
+use anyhow::{anyhow, Context, Result};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Known answers of a day, as stored in `answers/dayN.toml`:
+///
+/// ```toml
+/// part1 = 142
+/// part2 = "281"
+/// ```
+///
+/// Either part may be omitted if its answer is not known yet.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ExpectedAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl ExpectedAnswers {
+    /// Loads the expected answers from `path`, a missing file means that no answer is known.
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(s) => s
+                .parse()
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+}
+
+impl std::str::FromStr for ExpectedAnswers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let table: toml::Table = s.parse()?;
+
+        let answer = |key: &str| -> Result<Option<String>> {
+            match table.get(key) {
+                None => Ok(None),
+                Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+                Some(toml::Value::Integer(n)) => Ok(Some(n.to_string())),
+                Some(value) => Err(anyhow!("{key} must be a string or integer but was {value}")),
+            }
+        };
+
+        Ok(Self {
+            part1: answer("part1")?,
+            part2: answer("part2")?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn new(actual: &str, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[test]
+    fn parse_expected_answers() {
+        let answers: ExpectedAnswers = "part1 = 142\npart2 = \"281\"\n".parse().unwrap();
+        assert_eq!(
+            answers,
+            ExpectedAnswers {
+                part1: Some("142".to_owned()),
+                part2: Some("281".to_owned()),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_partial_expected_answers() {
+        let answers: ExpectedAnswers = "part1 = 142\n".parse().unwrap();
+        assert_eq!(answers.part1.as_deref(), Some("142"));
+        assert_eq!(answers.part2, None);
+    }
+
+    #[test]
+    fn parse_invalid_expected_answers() {
+        assert!("part1 = [1, 2]".parse::<ExpectedAnswers>().is_err());
+        assert!("part1 = ".parse::<ExpectedAnswers>().is_err());
+    }
+
+    #[test]
+    fn load_missing_expected_answers() {
+        let answers = ExpectedAnswers::load("answers/day0.toml").unwrap();
+        assert_eq!(answers, ExpectedAnswers::default());
+    }
+
+    #[rstest]
+    #[case("42", Some("42"), Verdict::Pass)]
+    #[case("42", Some("43"), Verdict::Fail { expected: "43".to_owned() })]
+    #[case("42", None, Verdict::Unknown)]
+    fn verdict_from_answers(
+        #[case] actual: &str,
+        #[case] expected: Option<&str>,
+        #[case] verdict: Verdict,
+    ) {
+        assert_eq!(Verdict::new(actual, expected), verdict);
+    }
+}
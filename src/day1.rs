@@ -1,18 +1,33 @@
+use crate::{Input, Solution};
 use anyhow::Result;
-use aoc23::read_lines;
 use regex::Regex;
 
-fn main() -> Result<()> {
-    let lines = read_lines("input/day1.txt")?;
-    println!("Part I: {}", callibration_value_part1(&lines));
-    println!("Part II: {}", callibration_value_part2(&lines));
+pub struct Puzzle {
+    lines: Vec<String>,
+}
+
+impl Solution for Puzzle {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &Input) -> Result<Self> {
+        Ok(Self {
+            lines: input.lines().to_vec(),
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        Ok(callibration_value_part1(&self.lines))
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<u32> {
+        Ok(callibration_value_part2(&self.lines))
+    }
 }
 
 fn callibration_value_part1(lines: &[String]) -> u32 {
     lines
-        .into_iter()
+        .iter()
         .filter_map(|s| {
             let first = s.chars().find(|c| c.is_numeric())?.to_digit(10)?;
             let last = s.chars().rfind(|c| c.is_numeric())?.to_digit(10)?;
@@ -46,7 +61,7 @@ fn callibration_value_part2(lines: &[String]) -> u32 {
     let re_rev = Regex::new(&format!("({})", PATTERN.chars().rev().collect::<String>())).unwrap();
 
     lines
-        .into_iter()
+        .iter()
         .filter_map(|s| {
             let rev_s = s.chars().rev().collect::<String>();
 
@@ -0,0 +1,504 @@
+use crate::geom::{Direction, Position};
+use crate::{Grid, Input, Solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::fmt::{self, Write};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    VerticalPipe,
+    HorizontalPipe,
+    NorthEastBend,
+    NorthWestBend,
+    SouthWestBend,
+    SouthEastBend,
+    Ground,
+    StartingPosition,
+}
+
+impl Tile {
+    /// Directions in which the tile connects to its neighbours.
+    pub fn connections(&self) -> &'static [Direction] {
+        match self {
+            Tile::VerticalPipe => &[Direction::North, Direction::South],
+            Tile::HorizontalPipe => &[Direction::West, Direction::East],
+            Tile::NorthEastBend => &[Direction::North, Direction::East],
+            Tile::NorthWestBend => &[Direction::North, Direction::West],
+            Tile::SouthEastBend => &[Direction::South, Direction::East],
+            Tile::SouthWestBend => &[Direction::South, Direction::West],
+            Tile::Ground => &[],
+            Tile::StartingPosition => &[
+                Direction::West,
+                Direction::East,
+                Direction::North,
+                Direction::South,
+            ],
+        }
+    }
+
+    /// The pipe connecting the two directions.
+    pub fn connecting(first: Direction, second: Direction) -> Option<Tile> {
+        [
+            Tile::VerticalPipe,
+            Tile::HorizontalPipe,
+            Tile::NorthEastBend,
+            Tile::NorthWestBend,
+            Tile::SouthWestBend,
+            Tile::SouthEastBend,
+        ]
+        .into_iter()
+        .find(|tile| {
+            let connections = tile.connections();
+            first != second && connections.contains(&first) && connections.contains(&second)
+        })
+    }
+}
+
+impl Tile {
+    pub fn box_drawing(&self) -> char {
+        match self {
+            Tile::VerticalPipe => '│',
+            Tile::HorizontalPipe => '─',
+            Tile::NorthEastBend => '└',
+            Tile::NorthWestBend => '┘',
+            Tile::SouthWestBend => '┐',
+            Tile::SouthEastBend => '┌',
+            Tile::Ground => '.',
+            Tile::StartingPosition => 'S',
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '|' => Ok(Tile::VerticalPipe),
+            '-' => Ok(Tile::HorizontalPipe),
+            'L' => Ok(Tile::NorthEastBend),
+            'J' => Ok(Tile::NorthWestBend),
+            '7' => Ok(Tile::SouthWestBend),
+            'F' => Ok(Tile::SouthEastBend),
+            '.' => Ok(Tile::Ground),
+            'S' => Ok(Tile::StartingPosition),
+            _ => Err(anyhow!("{value} is not a valid tile")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum LoopError {
+    NoStart,
+    MultipleStarts(Vec<Position>),
+    /// The start doesn't connect to exactly two neighbouring pipes.
+    AmbiguousStart(Vec<Direction>),
+    /// The pipe at the position doesn't connect back to the pipe it was entered from.
+    DeadEnd(Position),
+    /// The pipe at the position leads off the grid.
+    OffGrid(Position),
+}
+
+impl fmt::Display for LoopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoopError::NoStart => write!(f, "no starting position"),
+            LoopError::MultipleStarts(starts) => {
+                write!(
+                    f,
+                    "multiple starting positions at {}",
+                    starts.iter().join(", ")
+                )
+            }
+            LoopError::AmbiguousStart(connections) => write!(
+                f,
+                "starting position connects to {} pipes instead of 2",
+                connections.len()
+            ),
+            LoopError::DeadEnd(pos) => write!(f, "pipe at {pos} is a dead end"),
+            LoopError::OffGrid(pos) => write!(f, "pipe at {pos} leads off the grid"),
+        }
+    }
+}
+
+impl std::error::Error for LoopError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct PipeLoop {
+    /// The actual tile under the starting position.
+    start_tile: Tile,
+    /// Positions along the loop, beginning at the starting position.
+    path: Vec<Position>,
+}
+
+/// Directions from `pos` to the neighbouring pipes which connect back to `pos`.
+fn connected_directions(grid: &Grid<Tile>, pos: Position) -> Vec<Direction> {
+    Direction::CARDINAL
+        .into_iter()
+        .filter(|&direction| {
+            pos.step(direction)
+                .and_then(|neighbour| grid.get(neighbour))
+                .is_some_and(|tile| tile.connections().contains(&direction.opposite()))
+        })
+        .collect()
+}
+
+fn find_loop(grid: &Grid<Tile>) -> Result<PipeLoop, LoopError> {
+    let starts = grid
+        .iter()
+        .filter(|(_, &tile)| tile == Tile::StartingPosition)
+        .map(|(pos, _)| pos)
+        .collect_vec();
+    let start = match starts.as_slice() {
+        [] => return Err(LoopError::NoStart),
+        [start] => *start,
+        _ => return Err(LoopError::MultipleStarts(starts)),
+    };
+
+    let start_connections = connected_directions(grid, start);
+    let start_tile = match start_connections.as_slice() {
+        [first, second] => Tile::connecting(*first, *second).unwrap(),
+        _ => return Err(LoopError::AmbiguousStart(start_connections)),
+    };
+
+    // Every tile connects to exactly two neighbours and has to connect back to the previous one,
+    // so the walk can't revisit a tile before it is back at the start.
+    let mut path = vec![start];
+    let mut pos = start;
+    let mut direction = start_connections[0];
+    for _ in 0..grid.width() * grid.height() {
+        let next = pos
+            .step(direction)
+            .filter(|&next| grid.contains(next))
+            .ok_or(LoopError::OffGrid(pos))?;
+        if next == start {
+            return Ok(PipeLoop { start_tile, path });
+        }
+
+        let entry = direction.opposite();
+        let connections = grid[next].connections();
+        if !connections.contains(&entry) {
+            return Err(LoopError::DeadEnd(next));
+        }
+        direction = connections
+            .iter()
+            .copied()
+            .find(|&d| d != entry)
+            .ok_or(LoopError::DeadEnd(next))?;
+        path.push(next);
+        pos = next;
+    }
+    unreachable!("loop can't be longer than the number of tiles")
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum TileState {
+    Boundary,
+    Outside,
+    Inside,
+    Unknown,
+}
+
+/// Classifies every tile as part of the loop, inside or outside of it.
+fn classify_tiles(grid: &Grid<Tile>, loop_path: &[Position]) -> Grid<TileState> {
+    let width = grid.width();
+    let height = grid.height();
+
+    let mut tile_state = Grid::new(width * 2, height * 2, TileState::Unknown);
+
+    for (pos, next) in loop_path.iter().circular_tuple_windows() {
+        tile_state[Position::new(pos.x * 2, pos.y * 2)] = TileState::Boundary;
+        if next.y > pos.y {
+            tile_state[Position::new(pos.x * 2, pos.y * 2 + 1)] = TileState::Boundary;
+        }
+        if next.y < pos.y {
+            tile_state[Position::new(pos.x * 2, pos.y * 2 - 1)] = TileState::Boundary;
+        }
+        if next.x > pos.x {
+            tile_state[Position::new(pos.x * 2 + 1, pos.y * 2)] = TileState::Boundary;
+        }
+        if next.x < pos.x {
+            tile_state[Position::new(pos.x * 2 - 1, pos.y * 2)] = TileState::Boundary;
+        }
+    }
+
+    // Pad the grid so that the outside is connected even if the loop touches the border.
+    let mut tile_state = tile_state.padded(1, TileState::Unknown);
+
+    let mut queue_outside = Vec::new();
+    queue_outside.push(Position::new(0, 0));
+    tile_state[Position::new(0, 0)] = TileState::Outside;
+
+    while let Some(pos) = queue_outside.pop() {
+        for neighbour in tile_state.neighbours4(pos).collect_vec() {
+            if tile_state[neighbour] == TileState::Unknown {
+                tile_state[neighbour] = TileState::Outside;
+                queue_outside.push(neighbour);
+            }
+        }
+    }
+
+    let mut tile_state2 = Grid::new(width, height, TileState::Unknown);
+    for (pos, &state) in tile_state.iter() {
+        if pos.x == 0 || pos.y == 0 || pos.x > width * 2 || pos.y > height * 2 {
+            continue;
+        }
+        let pos = Position::new((pos.x - 1) / 2, (pos.y - 1) / 2);
+        if state == TileState::Boundary {
+            tile_state2[pos] = TileState::Boundary;
+        }
+        if tile_state2[pos] == TileState::Unknown {
+            tile_state2[pos] = state;
+        }
+    }
+
+    tile_state2.map(|&state| match state {
+        TileState::Unknown => TileState::Inside,
+        state => state,
+    })
+}
+
+fn tiles_in_loop(grid: &Grid<Tile>, loop_path: &[Position]) -> usize {
+    classify_tiles(grid, loop_path)
+        .iter()
+        .filter(|(_, &state)| state == TileState::Inside)
+        .count()
+}
+
+/// Number of tiles enclosed by the loop, from the polygon area of the loop path.
+///
+/// The shoelace formula gives the area `A` of the polygon through the tile centers and Pick's
+/// theorem `A = I + B/2 - 1` relates it to the `B` tiles on the loop and the `I` tiles inside.
+fn tiles_in_loop_shoelace(loop_path: &[Position]) -> usize {
+    let double_area = loop_path
+        .iter()
+        .circular_tuple_windows()
+        .map(|(p, q)| (p.x * q.y) as isize - (q.x * p.y) as isize)
+        .sum::<isize>()
+        .unsigned_abs();
+    (double_area + 2 - loop_path.len()) / 2
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EnclosedTilesMethod {
+    /// Flood-fills the outside of the loop on a grid of doubled resolution.
+    FloodFill,
+    /// Computes the area of the loop with the shoelace formula and Pick's theorem.
+    Shoelace,
+}
+
+pub struct Puzzle {
+    grid: Grid<Tile>,
+    pipe_loop: PipeLoop,
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        let grid = Grid::parse(input)?;
+        let pipe_loop = find_loop(&grid)?;
+        Ok(Self { grid, pipe_loop })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.pipe_loop.path.len() / 2)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.enclosed_tiles(EnclosedTilesMethod::FloodFill))
+    }
+}
+
+impl Puzzle {
+    pub fn enclosed_tiles(&self, method: EnclosedTilesMethod) -> usize {
+        match method {
+            EnclosedTilesMethod::FloodFill => tiles_in_loop(&self.grid, &self.pipe_loop.path),
+            EnclosedTilesMethod::Shoelace => tiles_in_loop_shoelace(&self.pipe_loop.path),
+        }
+    }
+
+    /// The tile at `pos`, with the starting position replaced by the actual tile under it.
+    fn loop_tile(&self, pos: Position) -> Tile {
+        if pos == self.pipe_loop.path[0] {
+            self.pipe_loop.start_tile
+        } else {
+            self.grid[pos]
+        }
+    }
+
+    /// Renders the loop with box-drawing characters, tiles inside of the loop as `I` and all
+    /// other tiles as `O`.
+    pub fn render_text(&self) -> String {
+        let tile_states = classify_tiles(&self.grid, &self.pipe_loop.path);
+        let mut text = String::new();
+        for (pos, state) in tile_states.iter() {
+            text.push(match state {
+                TileState::Boundary => self.loop_tile(pos).box_drawing(),
+                TileState::Inside => 'I',
+                TileState::Outside | TileState::Unknown => 'O',
+            });
+            if pos.x + 1 == tile_states.width() {
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    /// Renders the loop as SVG polygon through the tile centers, with the tiles inside of the
+    /// loop filled and the starting position marked.
+    pub fn render_svg(&self) -> String {
+        const TILE_SIZE: usize = 10;
+        let center = |pos: Position| {
+            (
+                pos.x * TILE_SIZE + TILE_SIZE / 2,
+                pos.y * TILE_SIZE + TILE_SIZE / 2,
+            )
+        };
+
+        let mut svg = String::new();
+        let (width, height) = (
+            self.grid.width() * TILE_SIZE,
+            self.grid.height() * TILE_SIZE,
+        );
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )
+        .unwrap();
+        writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+
+        let tile_states = classify_tiles(&self.grid, &self.pipe_loop.path);
+        for (pos, _) in tile_states
+            .iter()
+            .filter(|(_, &state)| state == TileState::Inside)
+        {
+            writeln!(
+                svg,
+                r#"<rect class="inside" x="{}" y="{}" width="{TILE_SIZE}" height="{TILE_SIZE}" fill="lightgreen"/>"#,
+                pos.x * TILE_SIZE,
+                pos.y * TILE_SIZE,
+            )
+            .unwrap();
+        }
+
+        let points = self
+            .pipe_loop
+            .path
+            .iter()
+            .map(|&pos| {
+                let (x, y) = center(pos);
+                format!("{x},{y}")
+            })
+            .join(" ");
+        writeln!(
+            svg,
+            r#"<polygon points="{points}" fill="none" stroke="black" stroke-width="2"/>"#
+        )
+        .unwrap();
+
+        let (x, y) = center(self.pipe_loop.path[0]);
+        writeln!(svg, r#"<circle cx="{x}" cy="{y}" r="3" fill="red"/>"#).unwrap();
+        writeln!(svg, "</svg>").unwrap();
+        svg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("input/day10_example1.txt", 8)]
+    #[case("input/day10_example2.txt", 8)]
+    fn farthest_loop_position(#[case] path: &str, #[case] expected: usize) {
+        let puzzle = Puzzle::parse(&Input::read(path).unwrap()).unwrap();
+        assert_eq!(puzzle.part1().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("input/day10_example3.txt", 4)]
+    #[case("input/day10_example4.txt", 8)]
+    #[case("input/day10_example5.txt", 10)]
+    fn enclosed_tiles(#[case] path: &str, #[case] expected: usize) {
+        let puzzle = Puzzle::parse(&Input::read(path).unwrap()).unwrap();
+        assert_eq!(puzzle.part2().unwrap(), expected);
+    }
+
+    #[rstest]
+    fn enclosed_tiles_methods_agree(
+        #[values(
+            "input/day10_example1.txt",
+            "input/day10_example2.txt",
+            "input/day10_example3.txt",
+            "input/day10_example4.txt",
+            "input/day10_example5.txt"
+        )]
+        path: &str,
+    ) {
+        let puzzle = Puzzle::parse(&Input::read(path).unwrap()).unwrap();
+        assert_eq!(
+            puzzle.enclosed_tiles(EnclosedTilesMethod::Shoelace),
+            puzzle.enclosed_tiles(EnclosedTilesMethod::FloodFill)
+        );
+    }
+
+    fn parse_grid(s: &str) -> Grid<Tile> {
+        Grid::parse(&Input::new("day10.txt", s)).unwrap()
+    }
+
+    #[test]
+    fn start_tile_is_inferred() {
+        let grid = Grid::parse(&Input::read("input/day10_example1.txt").unwrap()).unwrap();
+        let pipe_loop = find_loop(&grid).unwrap();
+        assert_eq!(pipe_loop.start_tile, Tile::SouthEastBend);
+        assert_eq!(pipe_loop.path[0], Position::new(0, 2));
+        assert_eq!(pipe_loop.path.len(), 16);
+    }
+
+    #[test]
+    fn loop_along_the_border() {
+        let grid = parse_grid("S-7\n|.|\nL-J");
+        let pipe_loop = find_loop(&grid).unwrap();
+        assert_eq!(pipe_loop.start_tile, Tile::SouthEastBend);
+        assert_eq!(pipe_loop.path.len(), 8);
+        assert_eq!(tiles_in_loop(&grid, &pipe_loop.path), 1);
+        assert_eq!(tiles_in_loop_shoelace(&pipe_loop.path), 1);
+    }
+
+    #[test]
+    fn render_text() {
+        let input = Input::new("day10.txt", "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...");
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.render_text(), "OO┌┐O\nO┌┘│O\n┌┘I└┐\n│┌──┘\n└┘OOO\n");
+
+        let input = Input::new("day10.txt", "S-7\n|.|\nL-J");
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.render_text(), "┌─┐\n│I│\n└─┘\n");
+    }
+
+    #[test]
+    fn render_svg() {
+        let input = Input::new("day10.txt", "S-7\n|.|\nL-J");
+        let puzzle = Puzzle::parse(&input).unwrap();
+        let svg = puzzle.render_svg();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches(r#"class="inside""#).count(), 1);
+        assert!(svg.contains(r#"points="5,5 15,5 25,5 25,15 25,25 15,25 5,25 5,15""#));
+    }
+
+    #[rstest]
+    #[case("S-7\n|.|\nL-J\n..S", LoopError::MultipleStarts(vec![Position::new(0, 0), Position::new(2, 3)]))]
+    #[case("F-7\n|.|\nL-J", LoopError::NoStart)]
+    #[case(".|.\n-S-\n.|.", LoopError::AmbiguousStart(Direction::CARDINAL.to_vec()))]
+    #[case("S-.\n...", LoopError::AmbiguousStart(vec![Direction::East]))]
+    #[case("S-7\n|.-\nL-J", LoopError::DeadEnd(Position::new(2, 1)))]
+    #[case("S-7\n|.L\nL-J", LoopError::OffGrid(Position::new(2, 1)))]
+    fn no_loop(#[case] grid: &str, #[case] expected: LoopError) {
+        assert_eq!(find_loop(&parse_grid(grid)), Err(expected));
+    }
+}
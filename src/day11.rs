@@ -0,0 +1,109 @@
+use crate::geom::Position;
+use crate::{Grid, Input, Solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Pixel {
+    Empty,
+    Galaxy,
+}
+
+impl TryFrom<char> for Pixel {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Pixel::Empty),
+            '#' => Ok(Pixel::Galaxy),
+            _ => Err(anyhow!("{c} is not a valid pixel")),
+        }
+    }
+}
+
+fn find_galaxies(space: &Grid<Pixel>, distances: &Grid<usize>) -> Vec<Position> {
+    space
+        .iter()
+        .filter(|(_, &pixel)| pixel == Pixel::Galaxy)
+        .map(|(pos, _)| {
+            let h = distances.row(pos.y).iter().take(pos.x).sum();
+            let v = distances.column(pos.x).take(pos.y).sum();
+            Position::new(h, v)
+        })
+        .collect()
+}
+
+fn expand_space(space: &Grid<Pixel>, mut distances: Grid<usize>, factor: usize) -> Grid<usize> {
+    for (y, row) in space.rows().enumerate() {
+        if row.contains(&Pixel::Galaxy) {
+            continue;
+        }
+
+        for x in 0..distances.width() {
+            distances[Position::new(x, y)] = factor;
+        }
+    }
+
+    for x in 0..space.width() {
+        if space.column(x).contains(&Pixel::Galaxy) {
+            continue;
+        }
+
+        for y in 0..distances.height() {
+            distances[Position::new(x, y)] = factor;
+        }
+    }
+
+    distances
+}
+
+fn sum_of_galaxy_distances(space: &Grid<Pixel>, factor: usize) -> usize {
+    let orig_distances = Grid::new(space.width(), space.height(), 1);
+    let distances = expand_space(space, orig_distances, factor);
+    let galaxies = find_galaxies(space, &distances);
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(&p1, &p2)| p1.manhattan_distance(p2))
+        .sum()
+}
+
+pub struct Puzzle {
+    space: Grid<Pixel>,
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        Ok(Self {
+            space: Grid::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(sum_of_galaxy_distances(&self.space, 2))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(sum_of_galaxy_distances(&self.space, 1000000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(2, 374)]
+    #[case(10, 1030)]
+    #[case(100, 8410)]
+    fn example(#[case] factor: usize, #[case] expected: usize) {
+        let input = Input::read("input/day11_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(sum_of_galaxy_distances(&puzzle.space, factor), expected);
+    }
+}
@@ -1,17 +1,28 @@
-use anyhow::Result;
-use aoc23::read_lines;
+use crate::{Input, ParseError, Solution};
+use anyhow::{anyhow, Result};
 use std::str::FromStr;
 
-fn main() -> Result<()> {
-    let lines = read_lines("input/day2.txt")?;
-    let games = parse_games(&lines);
+pub struct Puzzle {
+    games: Vec<Vec<CubeSet>>,
+}
 
-    let possible_game_ids = possible_games_part1(&games);
-    println!("Part I: {}", possible_game_ids.into_iter().sum::<usize>());
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Part II: {}", sum_of_power_of_cube_sets_part2(&games));
+    fn parse(input: &Input) -> Result<Self> {
+        Ok(Self {
+            games: parse_games(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(possible_games_part1(&self.games).into_iter().sum())
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<usize> {
+        Ok(sum_of_power_of_cube_sets_part2(&self.games))
+    }
 }
 
 fn possible_games_part1(games: &[Vec<CubeSet>]) -> Vec<usize> {
@@ -48,12 +59,12 @@ fn sum_of_power_of_cube_sets_part2(games: &[Vec<CubeSet>]) -> usize {
         .sum()
 }
 
-fn parse_games(lines: &[String]) -> Vec<Vec<CubeSet>> {
-    lines
-        .into_iter()
-        .map(|s| {
-            let (_, sets) = s.split_once(':').unwrap();
-            sets.split(';').map(|s| s.parse().unwrap()).collect()
+fn parse_games(input: &Input) -> Result<Vec<Vec<CubeSet>>, ParseError> {
+    input
+        .located_lines()
+        .map(|line| {
+            let (_, sets) = line.split_once(':')?;
+            sets.split(';').map(|s| line.parse(s)).collect()
         })
         .collect()
 }
@@ -69,15 +80,17 @@ impl FromStr for CubeSet {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        Ok(s.split(',').map(|s| s.trim()).fold(
+        s.split(',').map(|s| s.trim()).try_fold(
             CubeSet {
                 red: 0,
                 blue: 0,
                 green: 0,
             },
             |mut agg, s| {
-                let (n, color) = s.split_once(' ').unwrap();
-                let n: usize = n.parse().unwrap();
+                let (n, color) = s
+                    .split_once(' ')
+                    .ok_or(anyhow!("expected cube count and color but was '{s}'"))?;
+                let n: usize = n.parse().map_err(|_| anyhow!("invalid cube count '{n}'"))?;
                 match color {
                     "red" => {
                         agg.red += n;
@@ -88,11 +101,11 @@ impl FromStr for CubeSet {
                     "green" => {
                         agg.green += n;
                     }
-                    _ => {}
+                    _ => return Err(anyhow!("unknown cube color '{color}'")),
                 }
-                agg
+                Ok(agg)
             },
-        ))
+        )
     }
 }
 
@@ -195,6 +208,19 @@ mod tests {
         assert_eq!(cube_set, expected);
     }
 
+    #[test]
+    fn parse_games_error_location() {
+        let input = Input::new(
+            "day2.txt",
+            "Game 1: 3 blue; 2 red\nGame 2: 1 red; 2 purple, 1 blue",
+        );
+        let err = parse_games(&input).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 15);
+        assert_eq!(err.text, " 2 purple, 1 blue");
+        assert_eq!(err.message, "unknown cube color 'purple'");
+    }
+
     #[test]
     fn part1_example() {
         let games = example_games();
@@ -205,7 +231,6 @@ mod tests {
     #[test]
     fn part2_example() {
         let games = example_games();
-        let possible_games = sum_of_power_of_cube_sets_part2(&games);
-        assert_eq!(sum_of_power_of_cube_sets_part2(&EXAMPLE_GAMES), 2286);
+        assert_eq!(sum_of_power_of_cube_sets_part2(&games), 2286);
     }
 }
@@ -0,0 +1,144 @@
+use crate::geom::Position;
+use crate::{Grid, Input, ParseError, Solution};
+use anyhow::Result;
+use itertools::Itertools;
+use std::{collections::BTreeMap, ops::Range};
+
+pub struct Puzzle {
+    schematic: Schematic,
+    numbers: Vec<(Range<usize>, usize, usize)>,
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        let schematic = Schematic::parse(input)?;
+        let numbers = schematic.numbers();
+        Ok(Self { schematic, numbers })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self
+            .numbers
+            .iter()
+            .filter_map(|(x, y, n)| {
+                if self.schematic.is_symbol_adjacent(x.clone(), *y) {
+                    Some(n)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let mut gear_map: BTreeMap<Position, Vec<usize>> = BTreeMap::new();
+        for (x, y, n) in &self.numbers {
+            let adjacent_symbols = self.schematic.adjacent_symbols(x.clone(), *y);
+            for (pos, c) in adjacent_symbols {
+                if c != '*' {
+                    continue;
+                }
+                gear_map.entry(pos).or_default().push(*n);
+            }
+        }
+        Ok(gear_map
+            .values()
+            .filter(|v| v.len() == 2)
+            .map(|v| v[0] * v[1])
+            .sum())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol(char),
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        match c {
+            '.' => Cell::Empty,
+            '0'..='9' => Cell::Digit(c.to_digit(10).unwrap()),
+            _ => Cell::Symbol(c),
+        }
+    }
+}
+
+struct Schematic {
+    grid: Grid<Cell>,
+}
+
+impl Schematic {
+    pub fn parse(input: &Input) -> Result<Self, ParseError> {
+        Ok(Self {
+            grid: Grid::parse(input)?,
+        })
+    }
+
+    /// All numbers as `(x, y, number)` where `x` is the range of columns the number spans.
+    pub fn numbers(&self) -> Vec<(Range<usize>, usize, usize)> {
+        let mut numbers = Vec::new();
+        for (y, row) in self.grid.rows().enumerate() {
+            let mut current: Option<(Range<usize>, usize)> = None;
+            for (x, cell) in row.iter().enumerate() {
+                current = match (current, cell) {
+                    (Some((range, n)), Cell::Digit(d)) => {
+                        Some((range.start..x + 1, n * 10 + *d as usize))
+                    }
+                    (None, Cell::Digit(d)) => Some((x..x + 1, *d as usize)),
+                    (Some((range, n)), _) => {
+                        numbers.push((range, y, n));
+                        None
+                    }
+                    (None, _) => None,
+                };
+            }
+            if let Some((range, n)) = current {
+                numbers.push((range, y, n));
+            }
+        }
+        numbers
+    }
+
+    pub fn adjacent_symbols(&self, x: Range<usize>, y: usize) -> Vec<(Position, char)> {
+        x.flat_map(|x| self.grid.neighbours8(Position::new(x, y)))
+            .unique()
+            .filter_map(|pos| match self.grid[pos] {
+                Cell::Symbol(c) => Some((pos, c)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn is_symbol_adjacent(&self, x: Range<usize>, y: usize) -> bool {
+        !self.adjacent_symbols(x, y).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Input::read("input/day3_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.part1().unwrap(), 4361);
+        assert_eq!(puzzle.part2().unwrap(), 467835);
+    }
+
+    #[test]
+    fn numbers_at_row_end() {
+        let schematic = Schematic::parse(&Input::new("day3.txt", "12.\n.*3")).unwrap();
+        assert_eq!(schematic.numbers(), vec![(0..2, 0, 12), (2..3, 1, 3)]);
+        assert_eq!(
+            schematic.adjacent_symbols(2..3, 1),
+            vec![(Position::new(1, 1), '*')]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Input, ParseError, Solution};
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub struct Puzzle {
+    matching_numbers_per_card: Vec<usize>,
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        let cards = parse_cards(input)?;
+        Ok(Self {
+            matching_numbers_per_card: cards.iter().map(|c| c.mathing_numbers()).collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self
+            .matching_numbers_per_card
+            .iter()
+            .map(|&n| if n > 0 { 2usize.pow(n as u32 - 1) } else { 0 })
+            .sum())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let scratchcards = self.matching_numbers_per_card.iter().rfold(
+            Vec::with_capacity(self.matching_numbers_per_card.len()),
+            |mut scratchcards, &n| {
+                let suffix_sum: usize = (0..n.min(scratchcards.len()))
+                    .map(|i| scratchcards[i])
+                    .sum();
+                scratchcards.insert(0, suffix_sum + 1);
+                scratchcards
+            },
+        );
+        Ok(scratchcards.iter().sum())
+    }
+}
+
+fn parse_cards(input: &Input) -> Result<Vec<Card>, ParseError> {
+    input
+        .located_lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':')?;
+            let (winning_numbers, own_numbers) = numbers
+                .split_once('|')
+                .ok_or_else(|| line.error(numbers, "expected '|'"))?;
+            let parse_numbers = |s: &str| -> Result<HashSet<usize>, ParseError> {
+                s.split_whitespace().map(|s| line.parse(s)).collect()
+            };
+            Ok(Card {
+                winning_numbers: parse_numbers(winning_numbers)?,
+                own_numbers: parse_numbers(own_numbers)?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Card {
+    winning_numbers: HashSet<usize>,
+    own_numbers: HashSet<usize>,
+}
+
+impl Card {
+    pub fn mathing_numbers(&self) -> usize {
+        let overlap: std::collections::hash_set::Intersection<
+            '_,
+            usize,
+            std::collections::hash_map::RandomState,
+        > = self.winning_numbers.intersection(&self.own_numbers);
+        overlap.count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Input::read("input/day4_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.part1().unwrap(), 13);
+        assert_eq!(puzzle.part2().unwrap(), 30);
+    }
+
+    #[test]
+    fn parse_cards_error_location() {
+        let input = Input::new("day4.txt", "Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 3O");
+        let err = parse_cards(&input).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 20);
+        assert_eq!(err.text, "3O");
+    }
+}
@@ -0,0 +1,968 @@
+use crate::{Input, Line, ParseError, Solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use rangemap::{RangeMap, RangeSet};
+use std::{collections::HashMap, fmt, ops::Range, str::FromStr};
+
+pub struct Puzzle {
+    almanac: Almanac,
+    seeds: Vec<usize>,
+    /// The seeds read as pairs of start and length.
+    seed_ranges: Vec<Range<usize>>,
+    mapping_rules: MappingRules,
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        let almanac = Almanac::parse(input)?;
+        let seeds = almanac.seeds().to_vec();
+        if seeds.is_empty() {
+            return Err(anyhow!("no seeds"));
+        }
+        if seeds.len() % 2 != 0 {
+            return Err(anyhow!(
+                "odd number of seeds, expected pairs of start and length"
+            ));
+        }
+        let seed_ranges = seeds
+            .iter()
+            .tuples()
+            .map(|(&start, &len)| {
+                if len == 0 {
+                    return Err(anyhow!("seed range starting at {start} is empty"));
+                }
+                let end = start
+                    .checked_add(len)
+                    .ok_or_else(|| anyhow!("seed range {start} +{len} overflows"))?;
+                Ok(start..end)
+            })
+            .collect::<Result<_>>()?;
+        let mapping_rules = almanac.mapping(Category::Seed, Category::Location)?;
+
+        Ok(Self {
+            almanac,
+            seeds,
+            seed_ranges,
+            mapping_rules,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        self.seeds
+            .iter()
+            .map(|&seed| self.mapping_rules.resolve(seed))
+            .min()
+            .ok_or(anyhow!("no seeds"))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        self.seed_ranges
+            .iter()
+            .flat_map(|seeds| self.mapping_rules.resolve_range(seeds.clone()))
+            .map(|locations| locations.start)
+            .min()
+            .ok_or(anyhow!("no seeds"))
+    }
+}
+
+impl Puzzle {
+    pub fn almanac(&self) -> &Almanac {
+        &self.almanac
+    }
+
+    /// The combined mapping from seed to location.
+    pub fn mapping_rules(&self) -> &MappingRules {
+        &self.mapping_rules
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Category {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+impl FromStr for Category {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "seed" => Ok(Category::Seed),
+            "soil" => Ok(Category::Soil),
+            "fertilizer" => Ok(Category::Fertilizer),
+            "water" => Ok(Category::Water),
+            "light" => Ok(Category::Light),
+            "temperature" => Ok(Category::Temperature),
+            "humidity" => Ok(Category::Humidity),
+            "location" => Ok(Category::Location),
+            _ => Err(anyhow!("unknown category '{s}'")),
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Category::Seed => "seed",
+            Category::Soil => "soil",
+            Category::Fertilizer => "fertilizer",
+            Category::Water => "water",
+            Category::Light => "light",
+            Category::Temperature => "temperature",
+            Category::Humidity => "humidity",
+            Category::Location => "location",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The seeds and the maps between categories, every category maps to at most one other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: HashMap<Category, (Category, MappingRules)>,
+}
+
+impl Almanac {
+    /// Parses the almanac and validates that its maps chain from seed to location.
+    pub fn parse(input: &Input) -> Result<Self> {
+        let lines = input.located_lines().collect_vec();
+
+        let seeds_line = lines.first().ok_or(anyhow!("no seeds"))?;
+        let (_, seeds) = seeds_line.split_once(':')?;
+        let seeds = seeds
+            .split_whitespace()
+            .map(|s| seeds_line.parse(s))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        let mut maps = HashMap::new();
+        for section in lines[1..]
+            .split(|l| l.text().is_empty())
+            .filter(|section| !section.is_empty())
+        {
+            let header = &section[0];
+            let (source, destination) = parse_map_header(header)?;
+            let rules = parse_mapping_rules(&section[1..])?;
+            if maps.insert(source, (destination, rules)).is_some() {
+                return Err(header
+                    .error(header.text(), format!("{source} is mapped more than once"))
+                    .into());
+            }
+        }
+
+        let almanac = Self { seeds, maps };
+        let chain = almanac.chain(Category::Seed);
+        if chain.last() != Some(&Category::Location) {
+            return Err(anyhow!(
+                "maps don't connect seed to location: {}",
+                chain.iter().join(" -> ")
+            ));
+        }
+        Ok(almanac)
+    }
+
+    pub fn seeds(&self) -> &[usize] {
+        &self.seeds
+    }
+
+    /// Categories reachable from `start` by following the maps, beginning with `start`.
+    /// Stops before a category would be repeated.
+    pub fn chain(&self, start: Category) -> Vec<Category> {
+        let mut chain = vec![start];
+        let mut category = start;
+        while let Some((destination, _)) = self.maps.get(&category) {
+            if chain.contains(destination) {
+                break;
+            }
+            chain.push(*destination);
+            category = *destination;
+        }
+        chain
+    }
+
+    /// The combined mapping from `from` to `to`, which may also go against the direction of
+    /// the maps.
+    pub fn mapping(&self, from: Category, to: Category) -> Result<MappingRules> {
+        if let Some(rules) = self.forward_mapping(from, to)? {
+            Ok(rules)
+        } else if let Some(rules) = self.forward_mapping(to, from)? {
+            if !rules.is_injective() {
+                return Err(anyhow!(
+                    "{to} to {from} maps several numbers onto one, it can't be reversed"
+                ));
+            }
+            rules.invert()
+        } else {
+            Err(anyhow!("no mapping between {from} and {to}"))
+        }
+    }
+
+    fn forward_mapping(&self, from: Category, to: Category) -> Result<Option<MappingRules>> {
+        let chain = self.chain(from);
+        let Some(end) = chain.iter().position(|&category| category == to) else {
+            return Ok(None);
+        };
+        chain[..end]
+            .iter()
+            .map(|category| &self.maps[category].1)
+            .rev()
+            .try_fold(MappingRules::default(), |succ, r| {
+                r.merge_with_successor(&succ)
+            })
+            .map(Some)
+    }
+
+    pub fn resolve(&self, from: Category, to: Category, n: usize) -> Result<usize> {
+        Ok(self.mapping(from, to)?.resolve(n))
+    }
+}
+
+/// Parses a `<source>-to-<destination> map:` header.
+fn parse_map_header(line: &Line) -> Result<(Category, Category), ParseError> {
+    let expected = "expected '<source>-to-<destination> map:'";
+    let categories = line
+        .text()
+        .strip_suffix(" map:")
+        .ok_or_else(|| line.error(line.text(), expected))?;
+    let (source, destination) = categories
+        .split_once("-to-")
+        .ok_or_else(|| line.error(line.text(), expected))?;
+    Ok((line.parse(source)?, line.parse(destination)?))
+}
+
+fn parse_mapping_rules(lines: &[Line]) -> Result<MappingRules, ParseError> {
+    let mut rules = RangeMap::new();
+    for line in lines {
+        let rule: MappingRule = line.parse(line.text())?;
+        rules.insert(rule.source.clone(), rule);
+    }
+    Ok(MappingRules { rules })
+}
+
+/// Maps numbers of one category to another, numbers without a rule map to themselves.
+///
+/// Two sets of rules are equal if they map every number the same way, regardless of how
+/// their ranges are split.
+#[derive(Debug, Default, Clone)]
+pub struct MappingRules {
+    rules: RangeMap<usize, MappingRule>,
+}
+
+impl PartialEq for MappingRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalize().rules == other.normalize().rules
+    }
+}
+
+impl Eq for MappingRules {}
+
+impl MappingRules {
+    pub fn resolve(&self, n: usize) -> usize {
+        if let Some(rule) = self.rules.get(&n) {
+            rule.resolve(n)
+        } else {
+            n
+        }
+    }
+
+    /// Resolves all numbers of `range` at once, the result consists of one range per rule (or gap
+    /// between rules) overlapping with `range`.
+    pub fn resolve_range(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut resolved = Vec::new();
+
+        let mut start = range.start;
+        for (rule_range, rule) in self.rules.overlapping(&range) {
+            if start < rule_range.start {
+                resolved.push(start..rule_range.start);
+            }
+
+            let overlapping_range = start.max(rule_range.start)..range.end.min(rule_range.end);
+            let dest_start = rule.resolve(overlapping_range.start);
+            resolved.push(dest_start..dest_start + overlapping_range.len());
+
+            start = overlapping_range.end;
+        }
+        if start < range.end {
+            resolved.push(start..range.end);
+        }
+
+        resolved
+    }
+
+    /// The reverse mapping, from destination back to source.
+    ///
+    /// This is only exact if the rules are [injective](Self::is_injective). Fails if a rule's
+    /// offset can't be negated.
+    pub fn invert(&self) -> Result<MappingRules> {
+        let mut rules = RangeMap::new();
+        for (range, rule) in self.rules.iter() {
+            let dest_start = rule.resolve(range.start);
+            let source = dest_start..dest_start + range.len();
+            let offset = rule
+                .offset
+                .checked_neg()
+                .ok_or_else(|| anyhow!("can't invert shift by {}", rule.offset))?;
+            rules.insert(source.clone(), MappingRule { source, offset });
+        }
+        Ok(MappingRules { rules })
+    }
+
+    /// Whether no two numbers resolve to the same destination.
+    pub fn is_injective(&self) -> bool {
+        // no rule can reach usize::MAX, which always resolves to itself
+        let mut resolved = self.resolve_range(0..usize::MAX);
+        resolved.sort_by_key(|range| range.start);
+        resolved
+            .iter()
+            .tuple_windows()
+            .all(|(r1, r2)| r1.end <= r2.start)
+    }
+
+    /// All numbers which resolve into `range`, as sorted and non-overlapping ranges.
+    pub fn preimage(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut preimage = RangeSet::new();
+
+        for (rule_range, rule) in self.rules.iter() {
+            let dest_start = rule.resolve(rule_range.start);
+            let dest_range = dest_start..dest_start + rule_range.len();
+            let overlapping_range =
+                dest_range.start.max(range.start)..dest_range.end.min(range.end);
+            if !overlapping_range.is_empty() {
+                let source_start = rule_range.start + (overlapping_range.start - dest_start);
+                preimage.insert(source_start..source_start + overlapping_range.len());
+            }
+        }
+
+        // numbers without a rule resolve to themselves
+        for gap in self.rules.gaps(&range) {
+            preimage.insert(gap);
+        }
+
+        preimage.into_iter().collect()
+    }
+
+    /// The canonical form of these rules: contiguous ranges shifted by the same offset are
+    /// coalesced into one rule and rules mapping numbers onto themselves are dropped.
+    pub fn normalize(&self) -> MappingRules {
+        let mut normalized: Vec<MappingRule> = Vec::new();
+        for (range, rule) in self.rules.iter() {
+            if rule.offset == 0 {
+                continue;
+            }
+            match normalized.last_mut() {
+                Some(last) if last.source.end == range.start && last.offset == rule.offset => {
+                    last.source.end = range.end;
+                }
+                _ => normalized.push(MappingRule {
+                    source: range.clone(),
+                    offset: rule.offset,
+                }),
+            }
+        }
+        MappingRules {
+            rules: normalized
+                .into_iter()
+                .map(|rule| (rule.source.clone(), rule))
+                .collect(),
+        }
+    }
+
+    /// Combines these rules with the rules applied after them into a single mapping. Fails if
+    /// a combined shift overflows.
+    pub fn merge_with_successor(&self, successor: &MappingRules) -> Result<MappingRules> {
+        let mut combined_rules = self.rules.clone();
+
+        for (range, rule) in self.rules.iter() {
+            let dest_start = rule.resolve(range.start);
+            let dest_range = dest_start..dest_start + range.len();
+            let overlapping_ranges = successor.rules.overlapping(&dest_range);
+
+            for (successor_range, successor_rule) in overlapping_ranges {
+                let overlapping_range = (dest_range.start.max(successor_range.start))
+                    ..(dest_range.end.min(successor_range.end));
+
+                let source_start = range.start + (overlapping_range.start - dest_start);
+                let source_range = source_start..source_start + overlapping_range.len();
+                let offset = rule
+                    .offset
+                    .checked_add(successor_rule.offset)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "shift by {} followed by {} overflows",
+                            rule.offset,
+                            successor_rule.offset
+                        )
+                    })?;
+
+                combined_rules.insert(
+                    source_range.clone(),
+                    MappingRule {
+                        source: source_range,
+                        offset,
+                    },
+                );
+            }
+        }
+
+        for (range, rule) in successor.rules.iter() {
+            let overlapping_ranges = self.rules.overlapping(range);
+
+            let mut remaining_range = range.clone();
+
+            for (overlapping_range, _) in overlapping_ranges {
+                let range_before = remaining_range.start..overlapping_range.start;
+                if !range_before.is_empty() {
+                    combined_rules.insert(
+                        range_before.clone(),
+                        MappingRule {
+                            source: range_before.clone(),
+                            offset: rule.offset,
+                        },
+                    );
+                    remaining_range = range_before.end..remaining_range.end;
+                }
+
+                remaining_range = overlapping_range.end..remaining_range.end;
+            }
+
+            if !remaining_range.is_empty() {
+                combined_rules.insert(
+                    remaining_range.clone(),
+                    MappingRule {
+                        source: remaining_range,
+                        offset: rule.offset,
+                    },
+                );
+            }
+        }
+
+        let mut combined_rules_fixed = RangeMap::new();
+        for (range, mut rule) in combined_rules.into_iter() {
+            rule.source = range.clone();
+            combined_rules_fixed.insert(range, rule);
+        }
+
+        Ok(MappingRules {
+            rules: combined_rules_fixed,
+        })
+    }
+}
+
+/// Shifts all numbers of `source` by `offset`, the shifted range always fits into `usize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MappingRule {
+    source: Range<usize>,
+    offset: isize,
+}
+
+impl MappingRule {
+    /// Maps `source`, which must not be empty, onto the range starting at `dest_start`.
+    pub fn new(dest_start: usize, source: Range<usize>) -> Result<Self> {
+        if source.is_empty() {
+            return Err(anyhow!("empty source range"));
+        }
+        let offset = if dest_start >= source.start {
+            0isize.checked_add_unsigned(dest_start - source.start)
+        } else {
+            0isize.checked_sub_unsigned(source.start - dest_start)
+        }
+        .ok_or_else(|| anyhow!("shift from {} to {dest_start} overflows", source.start))?;
+        if source.end.checked_add_signed(offset).is_none() {
+            return Err(anyhow!("destination range overflows"));
+        }
+        Ok(Self { source, offset })
+    }
+
+    pub fn resolve(&self, n: usize) -> usize {
+        if self.source.contains(&n) {
+            n.checked_add_signed(self.offset)
+                .expect("shifted range is checked on construction")
+        } else {
+            n
+        }
+    }
+}
+
+impl FromStr for MappingRule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts: Vec<_> = s.split_whitespace().collect();
+        if parts.len() != 3 {
+            return Err(anyhow!("malformed mapping rule"));
+        }
+        let dest_start = parts[0].parse()?;
+        let src_start: usize = parts[1].parse()?;
+        let src_len: usize = parts[2].parse()?;
+        let src_end = src_start
+            .checked_add(src_len)
+            .ok_or_else(|| anyhow!("source range overflows"))?;
+        Self::new(dest_start, src_start..src_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::DAYS;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    #[test]
+    fn example() {
+        let input = Input::read("input/day5_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.part1().unwrap(), 35);
+        assert_eq!(puzzle.part2().unwrap(), 46);
+    }
+
+    #[test]
+    fn almanac_categories() {
+        let input = Input::read("input/day5_example.txt").unwrap();
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(almanac.seeds(), &[79, 14, 55, 13]);
+        assert_eq!(
+            almanac.chain(Category::Seed),
+            vec![
+                Category::Seed,
+                Category::Soil,
+                Category::Fertilizer,
+                Category::Water,
+                Category::Light,
+                Category::Temperature,
+                Category::Humidity,
+                Category::Location,
+            ]
+        );
+        assert_eq!(
+            almanac.chain(Category::Light),
+            vec![
+                Category::Light,
+                Category::Temperature,
+                Category::Humidity,
+                Category::Location,
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(Category::Seed, Category::Location, 79, 82)]
+    #[case(Category::Seed, Category::Soil, 79, 81)]
+    #[case(Category::Soil, Category::Humidity, 81, 78)]
+    #[case(Category::Humidity, Category::Soil, 78, 81)]
+    #[case(Category::Location, Category::Seed, 82, 79)]
+    #[case(Category::Water, Category::Water, 42, 42)]
+    fn resolve_between_categories(
+        #[case] from: Category,
+        #[case] to: Category,
+        #[case] n: usize,
+        #[case] expected: usize,
+    ) {
+        let input = Input::read("input/day5_example.txt").unwrap();
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(almanac.resolve(from, to, n).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("seeds: 1\n\nseed-to-dirt map:\n1 2 3", "unknown category 'dirt'")]
+    #[case(
+        "seeds: 1\n\nseed to soil map:\n1 2 3",
+        "expected '<source>-to-<destination> map:'"
+    )]
+    #[case(
+        "seeds: 1\n\nseed-to-location map:\n1 2 3\n\nseed-to-soil map:\n1 2 3",
+        "seed is mapped more than once"
+    )]
+    #[case(
+        "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nwater-to-location map:\n1 2 3",
+        "maps don't connect seed to location: seed -> soil"
+    )]
+    #[case(
+        "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3",
+        "maps don't connect seed to location: seed -> soil"
+    )]
+    fn invalid_almanac(#[case] text: &str, #[case] message: &str) {
+        let err = Almanac::parse(&Input::new("day5.txt", text)).unwrap_err();
+        assert!(err.to_string().contains(message), "{err}");
+    }
+
+    #[test]
+    fn empty_mapping_rule_is_located() {
+        let input = Input::new(
+            "day5.txt",
+            "seeds: 1 1\n\nseed-to-location map:\n1 2 3\n5 1 0",
+        );
+        let err = DAYS[4].solve(&input).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert!(err.message.contains("empty source range"), "{err}");
+    }
+
+    #[rstest]
+    #[case("seeds:\n\nseed-to-location map:\n1 2 3", "no seeds")]
+    #[case("seeds: 79\n\nseed-to-location map:\n1 2 3", "odd number of seeds")]
+    #[case(
+        "seeds: 79 14 55\n\nseed-to-location map:\n1 2 3",
+        "odd number of seeds"
+    )]
+    #[case("seeds: 1 0\n\nseed-to-location map:\n1 2 3", "is empty")]
+    #[case("seeds: 79 14 1 0\n\nseed-to-location map:\n1 2 3", "is empty")]
+    fn invalid_seeds(#[case] text: &str, #[case] message: &str) {
+        let err = Puzzle::parse(&Input::new("day5.txt", text)).err().unwrap();
+        assert!(err.to_string().contains(message), "{err}");
+    }
+
+    #[rstest]
+    #[case(0..200)]
+    #[case(79..93)]
+    #[case(55..68)]
+    #[case(50..51)]
+    #[case(99..101)]
+    #[case(42..42)]
+    fn resolve_range_matches_resolve(#[case] range: Range<usize>) {
+        let input = Input::read("input/day5_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        let rules = &puzzle.mapping_rules;
+
+        let resolved = rules.resolve_range(range.clone());
+        assert_eq!(resolved.iter().map(|r| r.len()).sum::<usize>(), range.len());
+        assert_eq!(
+            resolved.into_iter().flatten().collect_vec(),
+            range.map(|n| rules.resolve(n)).collect_vec()
+        );
+    }
+
+    #[test]
+    fn invert_example() {
+        let input = Input::read("input/day5_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        let rules = &puzzle.mapping_rules;
+        let inverted = rules.invert().unwrap();
+
+        for n in 0..1000 {
+            assert_eq!(rules.resolve(inverted.resolve(n)), n);
+            assert_eq!(inverted.resolve(rules.resolve(n)), n);
+        }
+        assert_eq!(inverted.resolve(46), 82);
+    }
+
+    #[rstest]
+    #[case(0..100)]
+    #[case(46..47)]
+    #[case(35..60)]
+    #[case(150..300)]
+    #[case(10..10)]
+    fn preimage_example(#[case] range: Range<usize>) {
+        let input = Input::read("input/day5_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        let rules = &puzzle.mapping_rules;
+
+        let preimage = rules.preimage(range.clone());
+        for n in 0..1000 {
+            assert_eq!(
+                preimage.iter().any(|r| r.contains(&n)),
+                range.contains(&rules.resolve(n)),
+                "seed {n}"
+            );
+        }
+        assert!(preimage
+            .iter()
+            .tuple_windows()
+            .all(|(r1, r2)| r1.end < r2.start));
+    }
+
+    #[test]
+    fn preimage_of_non_injective_rules() {
+        let rules = {
+            let mut rules = RangeMap::new();
+            rules.insert(10..20, MappingRule::new(0, 10..20).unwrap());
+            MappingRules { rules }
+        };
+
+        // 0..5 maps to itself but 10..15 maps onto it as well
+        assert_eq!(rules.preimage(0..5), vec![0..5, 10..15]);
+        // 15..20 is mapped away to 5..10
+        assert_eq!(rules.preimage(15..25), vec![20..25]);
+        assert!(!rules.is_injective());
+    }
+
+    #[test]
+    fn reverse_mapping_requires_injective_maps() {
+        let input = Input::read("input/day5_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert!(puzzle.mapping_rules.is_injective());
+
+        // seeds 0 and 10 both end up at location 0
+        let input = Input::new("day5.txt", "seeds: 1 1\n\nseed-to-location map:\n0 10 5");
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(
+            almanac
+                .resolve(Category::Seed, Category::Location, 10)
+                .unwrap(),
+            0
+        );
+        let err = almanac
+            .resolve(Category::Location, Category::Seed, 0)
+            .unwrap_err();
+        assert!(err.to_string().contains("can't be reversed"), "{err}");
+    }
+
+    #[test]
+    fn test_merging_of_mapping_rules() {
+        let rules1 = {
+            let mut rules = RangeMap::new();
+            rules.insert(
+                98..100,
+                MappingRule::new(50, 98..100).unwrap(), // 50..52,
+            );
+            rules.insert(
+                50..98,
+                MappingRule::new(52, 50..98).unwrap(), // 52..100,
+            );
+            MappingRules { rules }
+        };
+
+        let rules2 = {
+            let mut rules = RangeMap::new();
+            rules.insert(15..52, MappingRule::new(0, 15..52).unwrap());
+            rules.insert(52..54, MappingRule::new(37, 52..54).unwrap());
+            rules.insert(0..15, MappingRule::new(39, 0..15).unwrap());
+            MappingRules { rules }
+        };
+
+        let rules_expected = {
+            let mut rules = RangeMap::new();
+
+            // succ 15..52
+            // -> 15..50
+            rules.insert(15..50, MappingRule::new(0, 15..50).unwrap());
+            // -> 50..52 (overlaps with 98..100)
+            rules.insert(
+                98..100,
+                MappingRule::new(35, 98..100).unwrap(), // 0 + 50 - 15,
+            );
+
+            // succ 52..54 (partially overlaps with 50..98)
+            rules.insert(50..52, MappingRule::new(37, 50..52).unwrap());
+
+            // rest of 50..98 from 52..98
+            rules.insert(
+                52..98,
+                MappingRule::new(54, 52..98).unwrap(), // 52 + 52-50,
+            );
+
+            // succ 0..15
+            rules.insert(0..15, MappingRule::new(39, 0..15).unwrap());
+
+            MappingRules { rules }
+        };
+        for n in 0..1000 {
+            let a = rules2.resolve(rules1.resolve(n));
+            let b = rules_expected.resolve(n);
+            assert_eq!(a, b);
+        }
+        assert_eq!(
+            rules1.merge_with_successor(&rules2).unwrap(),
+            rules_expected
+        );
+    }
+
+    #[test]
+    fn test_merging_of_mapping_rules2() {
+        let rules1 = {
+            let mut rules = RangeMap::new();
+            rules.insert(
+                77..100,
+                MappingRule::new(45, 77..100).unwrap(), // 45..68,
+            );
+            MappingRules { rules }
+        };
+
+        let rules2 = {
+            let mut rules = RangeMap::new();
+            rules.insert(
+                55..69,
+                MappingRule::new(5, 55..69).unwrap(), // 5..19,
+            );
+            MappingRules { rules }
+        };
+
+        let rules_expected = {
+            let mut rules = RangeMap::new();
+
+            // succ 55..69
+            rules.insert(55..69, MappingRule::new(5, 55..69).unwrap());
+
+            // 77..100
+            // -> 77..87
+            rules.insert(77..87, MappingRule::new(45, 77..87).unwrap());
+            // -> 87..100 overlaps with succ 55..68
+            rules.insert(87..100, MappingRule::new(5, 87..100).unwrap());
+
+            MappingRules { rules }
+        };
+
+        for n in 0..1000 {
+            let a = rules2.resolve(rules1.resolve(n));
+            let b = rules_expected.resolve(n);
+            assert_eq!(a, b);
+        }
+
+        assert_eq!(
+            rules1.merge_with_successor(&rules2).unwrap(),
+            rules_expected
+        );
+    }
+
+    #[test]
+    fn normalize_coalesces_and_drops_identity() {
+        let rules = mapping_rules(&[
+            (50, 10, 5),  // 10..15 -> 50..55
+            (55, 15, 5),  // 15..20 -> 55..60, same shift as above
+            (20, 20, 10), // identity
+            (0, 30, 5),   // 30..35 -> 0..5
+            (10, 35, 5),  // 35..40 -> 10..15, adjacent but a different shift
+        ]);
+        assert_eq!(
+            rules.normalize().rules,
+            mapping_rules(&[(50, 10, 10), (0, 30, 5), (10, 35, 5)]).rules
+        );
+        assert_eq!(MappingRules::default().normalize().rules, RangeMap::new());
+        assert_eq!(
+            mapping_rules(&[(5, 5, 10)]).normalize().rules,
+            RangeMap::new()
+        );
+    }
+
+    #[test]
+    fn equality_ignores_how_ranges_are_split() {
+        assert_eq!(
+            mapping_rules(&[(50, 10, 5), (55, 15, 5), (20, 20, 10)]),
+            mapping_rules(&[(50, 10, 10)])
+        );
+        assert_eq!(mapping_rules(&[(7, 7, 3)]), MappingRules::default());
+        assert_ne!(
+            mapping_rules(&[(50, 10, 10)]),
+            mapping_rules(&[(50, 10, 9)])
+        );
+    }
+
+    #[rstest]
+    #[case(format!("0 {} 2", usize::MAX), "source range overflows")]
+    #[case(format!("{} {} 3", usize::MAX - 1, usize::MAX - 3), "destination range overflows")]
+    #[case(format!("{} 0 1", usize::MAX - 5), "overflows")]
+    #[case(format!("0 {} 1", usize::MAX - 5), "overflows")]
+    #[case("1 2".to_owned(), "malformed mapping rule")]
+    #[case("5 1 0".to_owned(), "empty source range")]
+    fn mapping_rule_overflow(#[case] rule: String, #[case] message: &str) {
+        let err = rule.parse::<MappingRule>().unwrap_err();
+        assert!(err.to_string().contains(message), "{err}");
+    }
+
+    #[test]
+    fn mapping_rule_at_numeric_boundaries() {
+        let max = usize::MAX;
+
+        let rule: MappingRule = format!("{} {} 10", max - 10, max - 20).parse().unwrap();
+        assert_eq!(rule.resolve(max - 11), max - 1);
+        assert_eq!(rule.resolve(max - 10), max - 10);
+
+        let rule: MappingRule = format!("{} {} 10", max - 110, max - 10).parse().unwrap();
+        assert_eq!(rule.resolve(max - 10), max - 110);
+        assert_eq!(rule.resolve(max - 1), max - 101);
+        assert_eq!(rule.resolve(max), max);
+
+        let rule: MappingRule = format!("{} 0 1", isize::MAX).parse().unwrap();
+        assert_eq!(rule.resolve(0), isize::MAX as usize);
+    }
+
+    #[test]
+    fn seed_ranges_at_numeric_boundaries() {
+        let max = usize::MAX;
+        let almanac = |seeds: String| {
+            Input::new(
+                "day5.txt",
+                &format!("seeds: {seeds}\n\nseed-to-location map:\n1 2 3"),
+            )
+        };
+
+        let puzzle = Puzzle::parse(&almanac(format!("{} 3", max - 3))).unwrap();
+        assert_eq!(puzzle.seed_ranges, vec![max - 3..max]);
+        assert_eq!(puzzle.part2().unwrap(), max - 3);
+
+        let err = Puzzle::parse(&almanac(format!("{} 10", max - 3)))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("overflows"), "{err}");
+    }
+
+    #[test]
+    fn merge_and_invert_overflow() {
+        let half = isize::MAX as usize;
+        let first = mapping_rules(&[(half, 0, 1)]);
+        let successor = mapping_rules(&[(2 * half, half, 1)]);
+        assert!(first.merge_with_successor(&successor).is_err());
+
+        // shifting down by 2^63 is fine, but back up by 2^63 isn't representable
+        let rules = mapping_rules(&[(0, half + 1, 1)]);
+        assert_eq!(rules.resolve(half + 1), 0);
+        assert!(rules.invert().is_err());
+    }
+
+    /// Rules as `(dest_start, source_start, len)`, inserted in order so later rules shadow
+    /// earlier ones where they overlap.
+    fn mapping_rules(rules: &[(usize, usize, usize)]) -> MappingRules {
+        let mut map = RangeMap::new();
+        for &(dest_start, source_start, len) in rules {
+            let source = source_start..source_start + len;
+            map.insert(
+                source.clone(),
+                MappingRule::new(dest_start, source).unwrap(),
+            );
+        }
+        MappingRules { rules: map }
+    }
+
+    fn rules_strategy() -> impl Strategy<Value = Vec<(usize, usize, usize)>> {
+        prop::collection::vec((0..120usize, 0..120usize, 1..40usize), 0..6)
+    }
+
+    proptest! {
+        #[test]
+        fn merge_with_successor_resolves_like_both_in_sequence(
+            first in rules_strategy(),
+            successor in rules_strategy(),
+        ) {
+            let first = mapping_rules(&first);
+            let successor = mapping_rules(&successor);
+            let merged = first.merge_with_successor(&successor).unwrap();
+            for n in 0..200 {
+                prop_assert_eq!(
+                    merged.resolve(n),
+                    successor.resolve(first.resolve(n)),
+                    "resolving {}", n
+                );
+            }
+        }
+
+        #[test]
+        fn normalize_preserves_resolve(rules in rules_strategy()) {
+            let rules = mapping_rules(&rules);
+            let normalized = rules.normalize();
+            for n in 0..200 {
+                prop_assert_eq!(normalized.resolve(n), rules.resolve(n), "resolving {}", n);
+            }
+            prop_assert_eq!(normalized.normalize().rules, normalized.rules);
+        }
+    }
+}
@@ -0,0 +1,448 @@
+use crate::{Input, Line, ParseError, Solution};
+use anyhow::{anyhow, Result};
+use std::ops::Range;
+
+#[derive(Debug)]
+struct Race {
+    time: usize,
+    record_distance: usize,
+}
+
+impl Race {
+    fn wins(&self, push_time: usize) -> bool {
+        let distance = push_time as u128 * self.time.saturating_sub(push_time) as u128;
+        distance > self.record_distance as u128
+    }
+
+    fn winning_push_times(&self) -> Range<usize> {
+        // dist = push_time * (time - push_time), which beats the record between the roots of
+        // push_time^2 - time * push_time + record = 0, i.e. (time +- sqrt(time^2 - 4 record)) / 2.
+        // The distance peaks at time / 2 and is symmetric around it, so if the peak doesn't win
+        // nothing does.
+        let best_push_time = self.time / 2;
+        if !self.wins(best_push_time) {
+            return 0..0;
+        }
+
+        let time = self.time as u128;
+        let discriminant = time * time - 4 * self.record_distance as u128;
+        // the integer square root is only off by rounding, so this is within one of the first
+        // winning push time
+        let mut min_push_time = ((time - discriminant.isqrt()) / 2) as usize;
+        while !self.wins(min_push_time) {
+            min_push_time += 1;
+        }
+        while min_push_time > 0 && self.wins(min_push_time - 1) {
+            min_push_time -= 1;
+        }
+
+        min_push_time..self.time - min_push_time + 1
+    }
+
+    /// The push times with which `boat` beats the record.
+    fn winning_push_times_with(&self, boat: &Boat) -> Range<usize> {
+        // Before reaching its max speed the boat travels acceleration * push_time * (time -
+        // push_time), which beats the record iff push_time * (time - push_time) beats
+        // record / acceleration (rounded down), so this is the puzzle's race with rescaled
+        // numbers.
+        let Some(record_distance) = self.record_distance.checked_div(boat.acceleration) else {
+            return 0..0;
+        };
+        let time = self.time.saturating_sub(boat.start_delay);
+        let accelerating = Race {
+            time,
+            record_distance,
+        }
+        .winning_push_times();
+
+        let Some(max_speed) = boat.max_speed else {
+            return accelerating;
+        };
+        let push_time_to_max_speed = max_speed.div_ceil(boat.acceleration);
+        let accelerating = accelerating.start.min(push_time_to_max_speed)
+            ..accelerating.end.min(push_time_to_max_speed);
+
+        // Afterwards it travels max_speed * (time - push_time), which only shrinks when pushing
+        // longer.
+        let at_max_speed = match self.record_distance.checked_div(max_speed) {
+            Some(record_time) => push_time_to_max_speed..time.saturating_sub(record_time),
+            None => 0..0,
+        };
+
+        // the distance first grows and then shrinks, so the push times of both phases form a
+        // single range
+        match (accelerating.is_empty(), at_max_speed.is_empty()) {
+            (true, true) => 0..0,
+            (false, true) => accelerating,
+            (true, false) => at_max_speed,
+            (false, false) => accelerating.start..at_max_speed.end,
+        }
+    }
+}
+
+/// How the boat moves: it speeds up by `acceleration` for every millisecond the button is
+/// held, up to `max_speed`, and starts moving `start_delay` milliseconds after the button is
+/// released.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Boat {
+    pub acceleration: usize,
+    pub max_speed: Option<usize>,
+    pub start_delay: usize,
+}
+
+impl Default for Boat {
+    /// The boat of the puzzle.
+    fn default() -> Self {
+        Self {
+            acceleration: 1,
+            max_speed: None,
+            start_delay: 0,
+        }
+    }
+}
+
+impl Boat {
+    /// How far the boat gets in a race of `time` milliseconds when holding the button for
+    /// `push_time` milliseconds.
+    pub fn distance(&self, time: usize, push_time: usize) -> u128 {
+        let speed = push_time as u128 * self.acceleration as u128;
+        let speed = match self.max_speed {
+            Some(max_speed) => speed.min(max_speed as u128),
+            None => speed,
+        };
+        let moving_time = time
+            .saturating_sub(push_time)
+            .saturating_sub(self.start_delay);
+        speed * moving_time as u128
+    }
+}
+
+fn number_of_ways_to_win(races: &[Race], boat: &Boat) -> usize {
+    races
+        .iter()
+        .map(|race| race.winning_push_times_with(boat).len())
+        .product()
+}
+
+/// Parses the race sheet into one race per column and the single race obtained by ignoring
+/// the spaces between the numbers.
+fn parse_races(input: &Input) -> Result<(Vec<Race>, Race)> {
+    let mut lines = input.located_lines();
+    let time_line = lines.next().ok_or(anyhow!("missing 'Time:' line"))?;
+    let distance_line = lines.next().ok_or(anyhow!("missing 'Distance:' line"))?;
+
+    let (times, kerned_time) = parse_sheet_line(&time_line, "Time")?;
+    let (distances, kerned_distance) = parse_sheet_line(&distance_line, "Distance")?;
+    if times.len() != distances.len() {
+        return Err(distance_line
+            .error(
+                distance_line.text(),
+                format!(
+                    "expected {} distances but found {}",
+                    times.len(),
+                    distances.len()
+                ),
+            )
+            .into());
+    }
+
+    let races = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, record_distance)| Race {
+            time,
+            record_distance,
+        })
+        .collect();
+    let kerned_race = Race {
+        time: kerned_time,
+        record_distance: kerned_distance,
+    };
+    Ok((races, kerned_race))
+}
+
+/// Parses a `<label>: n1 n2 ...` line into its numbers and their concatenation.
+fn parse_sheet_line(line: &Line, label: &str) -> Result<(Vec<usize>, usize), ParseError> {
+    let (name, values) = line.split_once(':')?;
+    if name != label {
+        return Err(line.error(name, format!("expected '{label}'")));
+    }
+    let numbers = values
+        .split_whitespace()
+        .map(|s| line.parse(s))
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+    let kerned: String = values.split_whitespace().collect();
+    let kerned = kerned
+        .parse()
+        .map_err(|err| line.error(values.trim(), format!("kerned number {kerned}: {err}")))?;
+    Ok((numbers, kerned))
+}
+
+pub struct Puzzle {
+    races: Vec<Race>,
+    kerned_race: Race,
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        let (races, kerned_race) = parse_races(input)?;
+        Ok(Self { races, kerned_race })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.ways_to_win(&Boat::default()))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.kerned_ways_to_win(&Boat::default()))
+    }
+}
+
+impl Puzzle {
+    /// The product of the number of ways to win each race with `boat`.
+    pub fn ways_to_win(&self, boat: &Boat) -> usize {
+        number_of_ways_to_win(&self.races, boat)
+    }
+
+    /// The number of ways to win the kerned race with `boat`.
+    pub fn kerned_ways_to_win(&self, boat: &Boat) -> usize {
+        self.kerned_race.winning_push_times_with(boat).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::part1_first(Race {
+            time: 7,
+            record_distance: 9,
+        }, 2..6)]
+    #[case::part1_second(Race {
+            time: 15,
+            record_distance: 40,
+        }, 4..12)]
+    #[case::part1_third(Race {
+            time: 30,
+            record_distance: 200,
+        }, 11..20)]
+    #[case::part2(Race {
+            time: 71530,
+            record_distance: 940200,
+        }, 14..71517)]
+    fn race_winning_push_times(#[case] race: Race, #[case] expected_push_times: Range<usize>) {
+        assert_eq!(race.winning_push_times(), expected_push_times);
+    }
+
+    #[test]
+    fn winning_push_times_matches_brute_force() {
+        for time in 0..60 {
+            for record_distance in 0..time * time / 4 + 2 {
+                let race = Race {
+                    time,
+                    record_distance,
+                };
+                let mut winning = (0..=time).filter(|&t| t * (time - t) > record_distance);
+                let expected = match (winning.clone().next(), winning.next_back()) {
+                    (Some(min), Some(max)) => min..max + 1,
+                    _ => 0..0,
+                };
+                assert_eq!(race.winning_push_times(), expected, "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn winning_push_times_with_boat_matches_brute_force() {
+        let boats = (0..4).flat_map(|acceleration| {
+            [None, Some(0), Some(1), Some(3), Some(4), Some(10)]
+                .into_iter()
+                .flat_map(move |max_speed| {
+                    (0..4).map(move |start_delay| Boat {
+                        acceleration,
+                        max_speed,
+                        start_delay,
+                    })
+                })
+        });
+        for boat in boats {
+            for time in 0..25 {
+                for record_distance in 0..60 {
+                    let race = Race {
+                        time,
+                        record_distance,
+                    };
+                    let winning = (0..=time)
+                        .filter(|&t| boat.distance(time, t) > record_distance as u128)
+                        .collect::<Vec<_>>();
+                    let expected = match (winning.first(), winning.last()) {
+                        (Some(&min), Some(&max)) => min..max + 1,
+                        _ => 0..0,
+                    };
+                    assert_eq!(expected.len(), winning.len(), "not a single range");
+                    assert_eq!(
+                        race.winning_push_times_with(&boat),
+                        expected,
+                        "{race:?} {boat:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn default_boat_is_the_puzzle_boat() {
+        let input = Input::read("input/day6_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        let boat = Boat::default();
+        assert_eq!(puzzle.ways_to_win(&boat), 288);
+        assert_eq!(puzzle.kerned_ways_to_win(&boat), 71503);
+        for race in &puzzle.races {
+            assert_eq!(
+                race.winning_push_times_with(&boat),
+                race.winning_push_times()
+            );
+        }
+    }
+
+    #[test]
+    fn boat_variants() {
+        let race = Race {
+            time: 30,
+            record_distance: 200,
+        };
+        let faster = Boat {
+            acceleration: 2,
+            ..Boat::default()
+        };
+        // 2 * t * (30 - t) > 200 <=> t * (30 - t) > 100
+        assert_eq!(race.winning_push_times_with(&faster), 4..27);
+
+        let capped = Boat {
+            max_speed: Some(10),
+            ..Boat::default()
+        };
+        // 10 * (30 - t) > 200 <=> t < 10, but at t = 10 the boat only reaches speed 10
+        assert_eq!(race.winning_push_times_with(&capped), 0..0);
+
+        let delayed = Boat {
+            start_delay: 1,
+            ..Boat::default()
+        };
+        // t * (29 - t) > 200
+        assert_eq!(race.winning_push_times_with(&delayed), 12..18);
+    }
+
+    fn winning_push_times_float(race: &Race) -> Range<usize> {
+        let time = race.time as f64;
+        let dist = race.record_distance as f64;
+
+        let sqrt_val = (time.powf(2.0) - 4.0 * dist).sqrt();
+        let min_push_time = ((-time + sqrt_val) / -2.0 + 1.0).floor() as usize;
+        let max_push_time = ((-time - sqrt_val) / -2.0).ceil() as usize;
+
+        min_push_time..max_push_time
+    }
+
+    #[rstest]
+    #[case(44707080, 283113411341491)]
+    #[case(71530, 940200)]
+    #[case(1_000_000_007, 123_456_789_012_345)]
+    #[case(3_000_000_000, 1_000_000_000_000_000_000)]
+    fn winning_push_times_matches_float(#[case] time: usize, #[case] record_distance: usize) {
+        let race = Race {
+            time,
+            record_distance,
+        };
+        assert_eq!(race.winning_push_times(), winning_push_times_float(&race));
+    }
+
+    #[rstest]
+    #[case(10_000_000_000, 1)]
+    #[case(4_000_000_000, 1_999_999_999)]
+    #[case(6_000_000_000, 2_999_999_998)]
+    #[case(u32::MAX as usize, 1 << 30)]
+    fn winning_push_times_at_exact_boundaries(#[case] time: usize, #[case] push_time: usize) {
+        // a record set by pushing for exactly `push_time` can only be beaten by pushing longer
+        let race = Race {
+            time,
+            record_distance: push_time * (time - push_time),
+        };
+        let winning = race.winning_push_times();
+        assert_eq!(winning.start, push_time + 1);
+        assert!(race.wins(winning.start) && race.wins(winning.end - 1));
+        assert!(!race.wins(winning.start - 1) && !race.wins(winning.end));
+    }
+
+    #[test]
+    fn example() {
+        let input = Input::read("input/day6_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.part1().unwrap(), 288);
+        assert_eq!(puzzle.part2().unwrap(), 71503);
+    }
+
+    #[test]
+    fn parse_example_races() {
+        let input = Input::read("input/day6_example.txt").unwrap();
+        let (races, kerned_race) = parse_races(&input).unwrap();
+        assert_eq!(
+            races
+                .iter()
+                .map(|race| (race.time, race.record_distance))
+                .collect::<Vec<_>>(),
+            vec![(7, 9), (15, 40), (30, 200)]
+        );
+        assert_eq!(
+            (kerned_race.time, kerned_race.record_distance),
+            (71530, 940200)
+        );
+    }
+
+    #[rstest]
+    #[case("Time: 7 15\nDistance: 9 4O", 2, "invalid digit found in string")]
+    #[case("Time: 7 15\nDistance: 9", 2, "expected 2 distances but found 1")]
+    #[case("Time: 7 15\nRecord: 9 40", 2, "expected 'Distance'")]
+    #[case("Distance: 9 40\nTime: 7 15", 1, "expected 'Time'")]
+    #[case(
+        "Time: 99999999999 99999999999\nDistance: 1 2",
+        1,
+        "kerned number 9999999999999999999999"
+    )]
+    fn parse_races_error(#[case] text: &str, #[case] line: usize, #[case] message: &str) {
+        let err = parse_races(&Input::new("day6.txt", text)).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(err.line, line);
+        assert!(err.message.contains(message), "{err}");
+    }
+
+    #[test]
+    fn parse_races_missing_line() {
+        assert!(parse_races(&Input::new("day6.txt", "Time: 7 15")).is_err());
+    }
+
+    #[test]
+    fn part1_example() {
+        let races = vec![
+            Race {
+                time: 7,
+                record_distance: 9,
+            },
+            Race {
+                time: 15,
+                record_distance: 40,
+            },
+            Race {
+                time: 30,
+                record_distance: 200,
+            },
+        ];
+        assert_eq!(number_of_ways_to_win(&races, &Boat::default()), 288);
+    }
+}
@@ -0,0 +1,912 @@
+use crate::{Input, ParseError, Solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::{cmp::Ordering, collections::BTreeMap, fmt};
+
+/// Which cards a hand may consist of, how they are ordered and which of them are wild.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Ruleset {
+    /// Weakest card first.
+    card_order: Vec<char>,
+    wildcards: Vec<char>,
+    hand_size: usize,
+    ranking: Ranking,
+}
+
+/// How hands are ranked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Ranking {
+    /// Camel Cards: hands of any size are classified by groups of equal cards and ties are
+    /// broken by comparing the cards in their original order.
+    CamelCards,
+    /// Poker: every card has a rank and a suit (`C`, `D`, `H` or `S`), e.g. `TH`, straights and
+    /// flushes count and ties are broken by kickers. Hands have 5 cards and no wildcards.
+    Poker,
+}
+
+impl Ruleset {
+    pub fn new(card_order: &str, wildcards: &str) -> Result<Self> {
+        let card_order = card_order.chars().collect_vec();
+        if let Some(card) = card_order.iter().duplicates().next() {
+            return Err(anyhow!("{card} appears more than once in the card order"));
+        }
+        let wildcards = wildcards.chars().collect_vec();
+        if let Some(card) = wildcards.iter().find(|card| !card_order.contains(card)) {
+            return Err(anyhow!("wildcard {card} is not part of the card order"));
+        }
+        Ok(Self {
+            card_order,
+            wildcards,
+            hand_size: 5,
+            ranking: Ranking::CamelCards,
+        })
+    }
+
+    /// Changes the number of cards in a hand, which is 5 by default.
+    pub fn with_hand_size(self, hand_size: usize) -> Self {
+        Self { hand_size, ..self }
+    }
+
+    /// The rules of part I, without any wildcards.
+    pub fn standard() -> Self {
+        Self::new("23456789TJQKA", "").unwrap()
+    }
+
+    /// The rules of part II, jokers are wild but the weakest card on their own.
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", "J").unwrap()
+    }
+
+    /// Standard poker, aces are high but also form the lowest straight.
+    pub fn poker() -> Self {
+        Self {
+            ranking: Ranking::Poker,
+            ..Self::standard()
+        }
+    }
+
+    fn strength(&self, card: char) -> Option<usize> {
+        self.card_order.iter().position(|&c| c == card)
+    }
+
+    fn is_wild(&self, card: char) -> bool {
+        self.wildcards.contains(&card)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    FiveOfAKind,
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::Straight => "straight",
+            HandType::Flush => "flush",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::StraightFlush => "straight flush",
+            HandType::FiveOfAKind => "five of a kind",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl HandType {
+    /// Classifies hands of any size by their best five cards, e.g. three pairs are two pair.
+    fn classify(cards: &[char], ruleset: &Ruleset) -> Self {
+        let mut group_sizes = cards
+            .iter()
+            .filter(|&&card| !ruleset.is_wild(card))
+            .counts()
+            .into_values()
+            .sorted_by(|a, b| b.cmp(a))
+            .collect_vec();
+
+        // Wildcards are best used to enlarge the largest group: that's optimal for the largest
+        // group and doesn't shrink the second largest one. Using a wildcard for the second
+        // largest group instead only helps to get a full house, but then the largest group
+        // would be big enough for four of a kind.
+        let wildcards = cards.len() - group_sizes.iter().sum::<usize>();
+        match group_sizes.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None => group_sizes.push(wildcards),
+        }
+
+        let second_largest = group_sizes.get(1).copied().unwrap_or_default();
+        Self::from_group_sizes(group_sizes[0], second_largest)
+    }
+
+    /// Classifies by the sizes of the two largest groups of equal cards, wildcards included.
+    fn from_group_sizes(largest: usize, second_largest: usize) -> Self {
+        match (largest, second_largest) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// A hand evaluated under some [`Ruleset`], hands order by type first and then by the
+/// strength of their cards in order, or of their kickers for poker. Hands which tie are equal.
+#[derive(Debug, Clone)]
+pub struct Hand {
+    hand_type: HandType,
+    strengths: Vec<usize>,
+    cards: String,
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.hand_type, &self.strengths).cmp(&(other.hand_type, &other.strengths))
+    }
+}
+
+impl Hand {
+    pub fn new(cards: &str, ruleset: &Ruleset) -> Result<Self> {
+        match ruleset.ranking {
+            Ranking::CamelCards => Self::camel_cards(cards, ruleset),
+            Ranking::Poker => Self::poker(cards, ruleset),
+        }
+    }
+
+    fn camel_cards(cards: &str, ruleset: &Ruleset) -> Result<Self> {
+        let strengths = cards
+            .chars()
+            .map(|c| {
+                ruleset
+                    .strength(c)
+                    .ok_or(anyhow!("{c} is not a valid card"))
+            })
+            .collect::<Result<Vec<usize>>>()?;
+        if strengths.len() != ruleset.hand_size {
+            return Err(anyhow!(
+                "expected {} cards but was {}",
+                ruleset.hand_size,
+                strengths.len()
+            ));
+        }
+        Ok(Self {
+            hand_type: HandType::classify(&cards.chars().collect_vec(), ruleset),
+            strengths,
+            cards: cards.to_owned(),
+        })
+    }
+
+    fn poker(cards: &str, ruleset: &Ruleset) -> Result<Self> {
+        let parsed_cards = cards
+            .chars()
+            .chunks(2)
+            .into_iter()
+            .map(|card| {
+                let (rank, suit) = card
+                    .collect_tuple()
+                    .ok_or(anyhow!("expected a rank and a suit for every card"))?;
+                let strength = ruleset
+                    .strength(rank)
+                    .ok_or(anyhow!("{rank} is not a valid rank"))?;
+                if !"CDHS".contains(suit) {
+                    return Err(anyhow!("{suit} is not a valid suit"));
+                }
+                Ok((strength, suit))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if parsed_cards.len() != 5 {
+            return Err(anyhow!("expected 5 cards but was {}", parsed_cards.len()));
+        }
+        if let Some(&(strength, suit)) = parsed_cards.iter().duplicates().next() {
+            return Err(anyhow!(
+                "{}{suit} appears more than once",
+                ruleset.card_order[strength]
+            ));
+        }
+
+        // ranks grouped by equal rank, largest group first and higher ranks first within groups
+        // of equal size, which is also the order of the kickers
+        let groups = parsed_cards
+            .iter()
+            .map(|&(strength, _)| strength)
+            .counts()
+            .into_iter()
+            .map(|(strength, count)| (count, strength))
+            .sorted_by(|a, b| b.cmp(a))
+            .collect_vec();
+        let ranks = groups.iter().map(|&(_, strength)| strength).collect_vec();
+
+        let ace = ruleset.card_order.len() - 1;
+        let straight_high_card = match ranks[..] {
+            [high, .., low] if ranks.len() == 5 && high - low == 4 => Some(high),
+            // the ace is low in A-2-3-4-5
+            [high, 3, 2, 1, 0] if high == ace => Some(3),
+            _ => None,
+        };
+        let flush = parsed_cards.iter().map(|&(_, suit)| suit).all_equal();
+
+        let hand_type = match (straight_high_card, flush, groups[0].0, groups[1].0) {
+            (Some(_), true, _, _) => HandType::StraightFlush,
+            (_, _, 4, _) => HandType::FourOfAKind,
+            (_, _, 3, 2) => HandType::FullHouse,
+            (_, true, _, _) => HandType::Flush,
+            (Some(_), _, _, _) => HandType::Straight,
+            (_, _, 3, _) => HandType::ThreeOfAKind,
+            (_, _, 2, 2) => HandType::TwoPair,
+            (_, _, 2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        };
+        Ok(Self {
+            hand_type,
+            strengths: straight_high_card.map_or(ranks, |high| vec![high]),
+            cards: cards.to_owned(),
+        })
+    }
+
+    pub fn hand_type(&self) -> HandType {
+        self.hand_type
+    }
+
+    /// The packed form of this hand, if it fits.
+    pub fn key(&self) -> Option<HandKey> {
+        HandKey::pack(self.hand_type, self.strengths.iter().copied())
+    }
+
+    pub fn cards(&self) -> &str {
+        &self.cards
+    }
+}
+
+/// A hand packed into a `u32` which orders like the [`Hand`] it was made of: the type takes
+/// the highest 4 bits followed by 4 bits for the strength of each card (or kicker).
+///
+/// Hands of up to 7 cards and rulesets of up to 16 cards can be packed.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct HandKey(u32);
+
+impl HandKey {
+    const MAX_CARDS: usize = 7;
+    const MAX_STRENGTH: usize = 15;
+
+    fn pack(hand_type: HandType, strengths: impl IntoIterator<Item = usize>) -> Option<Self> {
+        let mut key = (hand_type as u32) << 28;
+        for (i, strength) in strengths.into_iter().enumerate() {
+            if i >= Self::MAX_CARDS || strength > Self::MAX_STRENGTH {
+                return None;
+            }
+            key |= (strength as u32) << (24 - 4 * i);
+        }
+        Some(Self(key))
+    }
+
+    /// Evaluates a Camel Cards hand straight into its key, without building a [`Hand`].
+    pub fn camel_cards(cards: &str, ruleset: &Ruleset) -> Result<Self> {
+        if ruleset.ranking != Ranking::CamelCards {
+            return Err(anyhow!("only Camel Cards hands can be packed directly"));
+        }
+        if ruleset.hand_size > Self::MAX_CARDS || ruleset.card_order.len() > Self::MAX_STRENGTH + 1
+        {
+            return Err(anyhow!("ruleset is too large to pack its hands"));
+        }
+
+        let mut key = 0;
+        let mut group_sizes = [0; Self::MAX_STRENGTH + 1];
+        let mut wildcards = 0;
+        let mut card_count = 0;
+        for card in cards.chars() {
+            let strength = ruleset
+                .strength(card)
+                .ok_or(anyhow!("{card} is not a valid card"))?;
+            if card_count < Self::MAX_CARDS {
+                key |= (strength as u32) << (24 - 4 * card_count);
+            }
+            if ruleset.is_wild(card) {
+                wildcards += 1;
+            } else {
+                group_sizes[strength] += 1;
+            }
+            card_count += 1;
+        }
+        if card_count != ruleset.hand_size {
+            return Err(anyhow!(
+                "expected {} cards but was {card_count}",
+                ruleset.hand_size
+            ));
+        }
+
+        let (mut largest, mut second_largest) = (0, 0);
+        for size in group_sizes {
+            if size > largest {
+                (largest, second_largest) = (size, largest);
+            } else if size > second_largest {
+                second_largest = size;
+            }
+        }
+        let hand_type = HandType::from_group_sizes(largest + wildcards, second_largest);
+
+        Ok(Self(key | (hand_type as u32) << 28))
+    }
+
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+/// Total winnings like [`total_winnings`], but ranks the hands by their [`HandKey`], which is
+/// a lot faster for large inputs.
+pub fn total_winnings_packed(input: &Input, ruleset: &Ruleset) -> Result<usize, ParseError> {
+    let mut hands = input
+        .located_lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ')?;
+            Ok((
+                HandKey::camel_cards(hand, ruleset).map_err(|err| line.error(hand, err))?,
+                line.parse::<usize>(bid)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    // stable, so that equal hands rank in input order like they do in a [`Report`]
+    hands.sort_by_key(|&(key, _)| key);
+    Ok(hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| (i + 1) * bid)
+        .sum())
+}
+
+/// Parses every line into a hand and its bid.
+fn parse_hands(input: &Input, ruleset: &Ruleset) -> Result<Vec<(Hand, usize)>, ParseError> {
+    input
+        .located_lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ')?;
+            Ok((
+                Hand::new(hand, ruleset).map_err(|err| line.error(hand, err))?,
+                line.parse(bid)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn total_winnings(input: &Input, ruleset: &Ruleset) -> Result<usize, ParseError> {
+    Ok(Report::new(input, ruleset)?.total_winnings())
+}
+
+/// A hand together with its bid and its rank among all hands.
+#[derive(Debug, Clone)]
+pub struct RankedHand {
+    /// 1-based line of the hand in the input.
+    pub line: usize,
+    /// 1-based rank, the weakest hand has rank 1.
+    pub rank: usize,
+    pub hand: Hand,
+    pub bid: usize,
+}
+
+impl RankedHand {
+    /// What the hand adds to the total winnings.
+    pub fn winnings(&self) -> usize {
+        self.rank * self.bid
+    }
+}
+
+/// A hand which is of a better type under another ruleset.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Upgrade {
+    pub line: usize,
+    pub cards: String,
+    pub from: HandType,
+    pub to: HandType,
+}
+
+/// How all hands of an input ranked under some [`Ruleset`].
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// Weakest hand first.
+    hands: Vec<RankedHand>,
+}
+
+impl Report {
+    pub fn new(input: &Input, ruleset: &Ruleset) -> Result<Self, ParseError> {
+        let hands = parse_hands(input, ruleset)?
+            .into_iter()
+            .enumerate()
+            .sorted_by(|(_, (h1, _)), (_, (h2, _))| h1.cmp(h2))
+            .enumerate()
+            .map(|(i, (line, (hand, bid)))| RankedHand {
+                line: line + 1,
+                rank: i + 1,
+                hand,
+                bid,
+            })
+            .collect();
+        Ok(Self { hands })
+    }
+
+    /// All hands by rank, weakest first.
+    pub fn hands(&self) -> &[RankedHand] {
+        &self.hands
+    }
+
+    pub fn total_winnings(&self) -> usize {
+        self.hands.iter().map(RankedHand::winnings).sum()
+    }
+
+    /// Number of hands of each type, types without any hands are left out.
+    pub fn hand_type_counts(&self) -> BTreeMap<HandType, usize> {
+        self.hands
+            .iter()
+            .map(|ranked| ranked.hand.hand_type())
+            .counts()
+            .into_iter()
+            .collect()
+    }
+
+    /// The hands which are of a better type in `other`, a report of the same input, in input
+    /// order.
+    pub fn upgrades_in(&self, other: &Report) -> Vec<Upgrade> {
+        let other_types: BTreeMap<usize, HandType> = other
+            .hands
+            .iter()
+            .map(|ranked| (ranked.line, ranked.hand.hand_type()))
+            .collect();
+        self.hands
+            .iter()
+            .sorted_by_key(|ranked| ranked.line)
+            .filter_map(|ranked| {
+                let from = ranked.hand.hand_type();
+                let to = *other_types.get(&ranked.line)?;
+                (to > from).then(|| Upgrade {
+                    line: ranked.line,
+                    cards: ranked.hand.cards().to_owned(),
+                    from,
+                    to,
+                })
+            })
+            .collect()
+    }
+
+    /// One line per hand by rank, preceded by a header.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("rank,cards,type,bid,winnings\n");
+        for ranked in &self.hands {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                ranked.rank,
+                ranked.hand.cards(),
+                ranked.hand.hand_type(),
+                ranked.bid,
+                ranked.winnings()
+            ));
+        }
+        csv
+    }
+}
+
+pub struct Puzzle {
+    standard: Report,
+    jokers: Report,
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        Ok(Self {
+            standard: Report::new(input, &Ruleset::standard())?,
+            jokers: Report::new(input, &Ruleset::jokers())?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.standard.total_winnings())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.jokers.total_winnings())
+    }
+}
+
+impl Puzzle {
+    /// The ranking of part I.
+    pub fn standard_report(&self) -> &Report {
+        &self.standard
+    }
+
+    /// The ranking of part II.
+    pub fn jokers_report(&self) -> &Report {
+        &self.jokers
+    }
+
+    /// The hands which jokers turned into a better type.
+    pub fn joker_upgrades(&self) -> Vec<Upgrade> {
+        self.standard.upgrades_in(&self.jokers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[test]
+    fn example() {
+        let input = Input::read("input/day7_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.part1().unwrap(), 6440);
+        assert_eq!(puzzle.part2().unwrap(), 5905);
+    }
+
+    #[test]
+    fn example_report() {
+        let input = Input::read("input/day7_example.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+
+        let report = puzzle.jokers_report();
+        assert_eq!(
+            report
+                .hands()
+                .iter()
+                .map(|ranked| (ranked.rank, ranked.hand.cards(), ranked.line))
+                .collect_vec(),
+            vec![
+                (1, "32T3K", 1),
+                (2, "KK677", 3),
+                (3, "T55J5", 2),
+                (4, "QQQJA", 5),
+                (5, "KTJJT", 4),
+            ]
+        );
+        assert_eq!(
+            report.hand_type_counts(),
+            BTreeMap::from([
+                (HandType::OnePair, 1),
+                (HandType::TwoPair, 1),
+                (HandType::FourOfAKind, 3),
+            ])
+        );
+        assert_eq!(
+            puzzle.standard_report().to_csv(),
+            "rank,cards,type,bid,winnings\n\
+             1,32T3K,one pair,765,765\n\
+             2,KTJJT,two pair,220,440\n\
+             3,KK677,two pair,28,84\n\
+             4,T55J5,three of a kind,684,2736\n\
+             5,QQQJA,three of a kind,483,2415\n"
+        );
+        assert_eq!(
+            puzzle.joker_upgrades(),
+            vec![
+                Upgrade {
+                    line: 2,
+                    cards: "T55J5".to_owned(),
+                    from: HandType::ThreeOfAKind,
+                    to: HandType::FourOfAKind,
+                },
+                Upgrade {
+                    line: 4,
+                    cards: "KTJJT".to_owned(),
+                    from: HandType::TwoPair,
+                    to: HandType::FourOfAKind,
+                },
+                Upgrade {
+                    line: 5,
+                    cards: "QQQJA".to_owned(),
+                    from: HandType::ThreeOfAKind,
+                    to: HandType::FourOfAKind,
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("32T3K", HandType::OnePair, HandType::OnePair)]
+    #[case("T55J5", HandType::ThreeOfAKind, HandType::FourOfAKind)]
+    #[case("KK677", HandType::TwoPair, HandType::TwoPair)]
+    #[case("KTJJT", HandType::TwoPair, HandType::FourOfAKind)]
+    #[case("QQQJA", HandType::ThreeOfAKind, HandType::FourOfAKind)]
+    #[case("JJJJJ", HandType::FiveOfAKind, HandType::FiveOfAKind)]
+    #[case("2345J", HandType::HighCard, HandType::OnePair)]
+    #[case("22J33", HandType::TwoPair, HandType::FullHouse)]
+    fn hand_types(#[case] cards: &str, #[case] standard: HandType, #[case] jokers: HandType) {
+        assert_eq!(
+            Hand::new(cards, &Ruleset::standard()).unwrap().hand_type(),
+            standard
+        );
+        assert_eq!(
+            Hand::new(cards, &Ruleset::jokers()).unwrap().hand_type(),
+            jokers
+        );
+    }
+
+    #[rstest]
+    #[case("JJ", 2, HandType::OnePair)]
+    #[case("2J3", 3, HandType::OnePair)]
+    #[case("22J", 3, HandType::ThreeOfAKind)]
+    #[case("223344", 6, HandType::TwoPair)]
+    #[case("222333", 6, HandType::FullHouse)]
+    #[case("22J3344", 7, HandType::FullHouse)]
+    #[case("2222223", 7, HandType::FiveOfAKind)]
+    #[case("JJJJJJ", 6, HandType::FiveOfAKind)]
+    fn hand_types_of_other_sizes(
+        #[case] cards: &str,
+        #[case] hand_size: usize,
+        #[case] expected: HandType,
+    ) {
+        let ruleset = Ruleset::jokers().with_hand_size(hand_size);
+        assert_eq!(Hand::new(cards, &ruleset).unwrap().hand_type(), expected);
+    }
+
+    /// The best type when trying every non-wild card in place of every wildcard.
+    fn best_substitution(cards: &[char], ruleset: &Ruleset) -> HandType {
+        let substitutes = ruleset
+            .card_order
+            .iter()
+            .copied()
+            .filter(|&card| !ruleset.is_wild(card))
+            .collect_vec();
+        cards
+            .iter()
+            .map(|&card| {
+                if ruleset.is_wild(card) {
+                    substitutes.clone()
+                } else {
+                    vec![card]
+                }
+            })
+            .multi_cartesian_product()
+            .map(|cards| count_hand_type(&cards))
+            .max()
+            .unwrap()
+    }
+
+    /// Classifies a hand without wildcards by directly counting equal cards, independently of
+    /// `HandType::classify`.
+    fn count_hand_type(cards: &[char]) -> HandType {
+        let count = |card: &char| cards.iter().filter(|&c| c == card).count();
+        let largest = cards.iter().map(count).max().unwrap_or_default();
+        let full_house = cards
+            .iter()
+            .any(|a| count(a) == 3 && cards.iter().any(|b| b != a && count(b) >= 2));
+        let pairs = cards
+            .iter()
+            .unique()
+            .filter(|card| count(card) == 2)
+            .count();
+
+        if largest >= 5 {
+            HandType::FiveOfAKind
+        } else if largest == 4 {
+            HandType::FourOfAKind
+        } else if full_house {
+            HandType::FullHouse
+        } else if largest == 3 {
+            HandType::ThreeOfAKind
+        } else if pairs >= 2 {
+            HandType::TwoPair
+        } else if pairs == 1 {
+            HandType::OnePair
+        } else {
+            HandType::HighCard
+        }
+    }
+
+    #[rstest]
+    // with at most 4 other cards in a hand with a wildcard, 4 other ranks cover every grouping
+    #[case("J2345", "J", 5)]
+    #[case("J2Q345", "JQ", 5)]
+    #[case("J234", "J", 3)]
+    #[case("J234", "J", 4)]
+    #[case("J234", "J", 6)]
+    fn wildcards_are_assigned_optimally(
+        #[case] card_order: &str,
+        #[case] wildcards: &str,
+        #[case] hand_size: usize,
+    ) {
+        let ruleset = Ruleset::new(card_order, wildcards)
+            .unwrap()
+            .with_hand_size(hand_size);
+        let hands = (0..hand_size)
+            .map(|_| ruleset.card_order.iter().copied())
+            .multi_cartesian_product();
+        for cards in hands {
+            assert_eq!(
+                HandType::classify(&cards, &ruleset),
+                best_substitution(&cards, &ruleset),
+                "{}",
+                cards.iter().collect::<String>()
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("ASKSQSJSTS", HandType::StraightFlush)]
+    #[case("5H4H3H2HAH", HandType::StraightFlush)]
+    #[case("9C9D9H9SKD", HandType::FourOfAKind)]
+    #[case("2C2D2HASAD", HandType::FullHouse)]
+    #[case("2D7D9DJDKD", HandType::Flush)]
+    #[case("6C5D4H3S2C", HandType::Straight)]
+    #[case("AC2D3H4S5C", HandType::Straight)]
+    #[case("QCKDAH2S3C", HandType::HighCard)]
+    #[case("7C7D7H2S3C", HandType::ThreeOfAKind)]
+    #[case("7C7D3H3S2C", HandType::TwoPair)]
+    #[case("7C7D3H4S2C", HandType::OnePair)]
+    #[case("7C8D3H4S2C", HandType::HighCard)]
+    fn poker_hand_types(#[case] cards: &str, #[case] expected: HandType) {
+        assert_eq!(
+            Hand::new(cards, &Ruleset::poker()).unwrap().hand_type(),
+            expected
+        );
+    }
+
+    #[rstest]
+    // the ace is low in the lowest straight
+    #[case("AC2D3H4S5C", "2C3D4H5S6C")]
+    // kickers decide between equal pairs
+    #[case("ACADQC5S2H", "AHASKD5C2D")]
+    // the triple decides between full houses, regardless of the pair
+    #[case("2C2D2HASAD", "3C3D3H2S2D")]
+    // the higher pair decides between two pairs, then the lower one, then the kicker
+    #[case("KCKDQCQD2H", "ACAD2C2D3H")]
+    #[case("KCKD2C2D3H", "KHKS3C3D2H")]
+    #[case("KCKD2C2D3H", "KHKS2H2S4H")]
+    // flushes compare all their cards, highest first
+    #[case("2D7D9DJDKD", "3H7H9HJHKH")]
+    fn poker_tiebreaks(#[case] weaker: &str, #[case] stronger: &str) {
+        let poker = Ruleset::poker();
+        assert!(Hand::new(weaker, &poker).unwrap() < Hand::new(stronger, &poker).unwrap());
+    }
+
+    #[test]
+    fn poker_ties_ignore_card_order_and_suits() {
+        let poker = Ruleset::poker();
+        assert_eq!(
+            Hand::new("ACADQC5S2H", &poker)
+                .unwrap()
+                .cmp(&Hand::new("2DQH5CAHAS", &poker).unwrap()),
+            Ordering::Equal
+        );
+    }
+
+    #[rstest]
+    #[case("ASKSQSJST", "expected a rank and a suit for every card")]
+    #[case("ASKSQSJS1S", "1 is not a valid rank")]
+    #[case("ASKSQSJSTX", "X is not a valid suit")]
+    #[case("ASKSQSJS", "expected 5 cards but was 4")]
+    #[case("ASKSQSKSTS", "KS appears more than once")]
+    fn invalid_poker_hand(#[case] cards: &str, #[case] message: &str) {
+        let err = Hand::new(cards, &Ruleset::poker()).unwrap_err();
+        assert_eq!(err.to_string(), message);
+    }
+
+    #[test]
+    fn poker_winnings() {
+        let input = Input::new("poker.txt", "2C3D4H5S6C 10\nACADQC5S2H 1\n2D7D9DJDKD 100");
+        assert_eq!(
+            total_winnings(&input, &Ruleset::poker()).unwrap(),
+            1 + 2 * 10 + 3 * 100
+        );
+    }
+
+    #[rstest]
+    #[case(Ruleset::standard())]
+    #[case(Ruleset::jokers())]
+    #[case(Ruleset::new("J2Q345", "JQ").unwrap())]
+    fn hand_keys_order_like_hands(#[case] ruleset: Ruleset) {
+        let hands = (0..5)
+            .map(|_| "J2Q345".chars())
+            .multi_cartesian_product()
+            .map(|cards| Hand::new(&cards.into_iter().collect::<String>(), &ruleset).unwrap())
+            .sorted()
+            .collect_vec();
+        for hand in &hands {
+            assert_eq!(
+                HandKey::camel_cards(hand.cards(), &ruleset).unwrap(),
+                hand.key().unwrap()
+            );
+        }
+        for (weaker, stronger) in hands.iter().tuple_windows() {
+            assert_eq!(weaker.key().cmp(&stronger.key()), weaker.cmp(stronger));
+        }
+    }
+
+    #[test]
+    fn poker_hand_keys_order_like_hands() {
+        let poker = Ruleset::poker();
+        let hands = [
+            "7C8D3H4S2C",
+            "7C7D3H4S2C",
+            "7C7D3H3S2C",
+            "7C7D7H2S3C",
+            "AC2D3H4S5C",
+            "6C5D4H3S2C",
+            "2D7D9DJDKD",
+            "2C2D2HASAD",
+            "3C3D3H2S2D",
+            "9C9D9H9SKD",
+            "ASKSQSJSTS",
+        ]
+        .map(|cards| Hand::new(cards, &poker).unwrap());
+        for (weaker, stronger) in hands.iter().tuple_windows() {
+            assert!(weaker < stronger);
+            assert!(weaker.key().unwrap() < stronger.key().unwrap());
+        }
+    }
+
+    #[test]
+    fn hand_keys_of_large_rulesets() {
+        let ruleset = Ruleset::new("0123456789ABCDEFG", "").unwrap();
+        assert!(Hand::new("G0000", &ruleset).unwrap().key().is_none());
+        assert!(HandKey::camel_cards("00000", &ruleset).is_err());
+        assert!(HandKey::camel_cards("ASKSQSJSTS", &Ruleset::poker()).is_err());
+    }
+
+    #[test]
+    fn packed_winnings() {
+        let input = Input::read("input/day7_example.txt").unwrap();
+        assert_eq!(
+            total_winnings_packed(&input, &Ruleset::standard()).unwrap(),
+            6440
+        );
+        assert_eq!(
+            total_winnings_packed(&input, &Ruleset::jokers()).unwrap(),
+            5905
+        );
+    }
+
+    #[test]
+    fn card_order_depends_on_ruleset() {
+        let standard = Ruleset::standard();
+        assert!(Hand::new("JKKK2", &standard).unwrap() < Hand::new("QKKK2", &standard).unwrap());
+        assert!(Hand::new("JJKK2", &standard).unwrap() > Hand::new("TTKK2", &standard).unwrap());
+        // both are four of a kind, but a joker on its own is weaker than a queen
+        let jokers = Ruleset::jokers();
+        assert!(Hand::new("JKKK2", &jokers).unwrap() < Hand::new("QQQQ2", &jokers).unwrap());
+    }
+
+    #[rstest]
+    #[case("23456789TJQKA", "X")]
+    #[case("23456789TJQKAA", "")]
+    fn invalid_ruleset(#[case] card_order: &str, #[case] wildcards: &str) {
+        assert!(Ruleset::new(card_order, wildcards).is_err());
+    }
+
+    #[test]
+    fn parse_hands_error_location() {
+        let input = Input::new("day7.txt", "32T3K 765\nT55X5 684");
+        let err = parse_hands(&input, &Ruleset::standard()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "X is not a valid card");
+    }
+}
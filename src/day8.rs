@@ -0,0 +1,746 @@
+use crate::{Input, Line, ParseError, Solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use num::integer::{ExtendedGcd, Integer};
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Instruction {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            _ => Err(anyhow!("unknown instruction {c}")),
+        }
+    }
+}
+
+/// Dense index of a node in a [`Network`].
+pub type NodeId = u32;
+
+/// The nodes of the map, with names interned into dense ids so that walking doesn't allocate.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Network {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    /// Left and right successor of every node, `None` for nodes which are only referenced.
+    successors: Vec<Option<[NodeId; 2]>>,
+}
+
+impl Network {
+    /// Parses `<node> = (<left>, <right>)` lines, skipping empty ones.
+    fn parse<'a>(lines: impl IntoIterator<Item = Line<'a>>) -> Result<Self, ParseError> {
+        let re = Regex::new(r"^([0-9A-Z]{3}) = \(([0-9A-Z]{3}), ([0-9A-Z]{3})\)$").unwrap();
+
+        let mut network = Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            successors: Vec::new(),
+        };
+        for line in lines.into_iter().filter(|line| !line.text().is_empty()) {
+            let (_, [node, left, right]) = re
+                .captures(line.text())
+                .ok_or_else(|| line.error(line.text(), "expected '<node> = (<left>, <right>)'"))?
+                .extract();
+            let node = network.intern(node);
+            let successors = [network.intern(left), network.intern(right)];
+            network.successors[node as usize] = Some(successors);
+        }
+        Ok(network)
+    }
+
+    fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len() as NodeId;
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        self.successors.push(None);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, node: NodeId) -> &str {
+        &self.names[node as usize]
+    }
+
+    /// All nodes, in order of their first appearance.
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len() as NodeId
+    }
+
+    fn next(&self, node: NodeId, instruction: Instruction) -> Result<NodeId> {
+        let Some([left, right]) = self.successors[node as usize] else {
+            return Err(self.dangling(node));
+        };
+        Ok(match instruction {
+            Instruction::Left => left,
+            Instruction::Right => right,
+        })
+    }
+
+    /// The error for a node which is referenced but never defined.
+    fn dangling(&self, node: NodeId) -> anyhow::Error {
+        let referrer = self
+            .nodes()
+            .find(|&other| self.successors[other as usize].is_some_and(|s| s.contains(&node)));
+        match referrer {
+            Some(referrer) => anyhow!(
+                "dangling node {} referenced by {}",
+                self.name(node),
+                self.name(referrer)
+            ),
+            None => anyhow!("dangling node {}", self.name(node)),
+        }
+    }
+
+    fn is_ghost_start(&self, node: NodeId) -> bool {
+        self.name(node).ends_with('A')
+    }
+
+    fn is_ghost_end(&self, node: NodeId) -> bool {
+        self.name(node).ends_with('Z')
+    }
+
+    fn ghost_starts(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes().filter(|&node| self.is_ghost_start(node))
+    }
+}
+
+/// The path from `start_node` to `end_node`. As soon as a node is reached at the same
+/// instruction index for the second time the walk would repeat forever, so the end node is
+/// unreachable.
+fn walk_until_end(
+    network: &Network,
+    instructions: &[Instruction],
+    start_node: NodeId,
+    end_node: NodeId,
+) -> Result<Vec<NodeId>> {
+    let mut visited = HashSet::new();
+    let mut path = vec![start_node];
+    let mut node = start_node;
+    for (instruction_index, &instruction) in instructions.iter().enumerate().cycle() {
+        if node == end_node {
+            return Ok(path);
+        }
+        if !visited.insert((node, instruction_index)) {
+            break;
+        }
+        node = network.next(node, instruction)?;
+        path.push(node);
+    }
+    // the walk repeats, or there are no instructions to leave the start node with
+    Err(anyhow!(
+        "target unreachable: {} is never reached from {}",
+        network.name(end_node),
+        network.name(start_node)
+    ))
+}
+
+/// Walks all ghosts at once until they are on end nodes at the same time, but gives up after
+/// `max_steps`. Only feasible for small networks, but it makes no assumptions at all.
+pub fn ghost_walk_until_end(
+    network: &Network,
+    instructions: &[Instruction],
+    max_steps: usize,
+) -> Result<Option<usize>> {
+    if instructions.is_empty() {
+        return Err(anyhow!("no instructions"));
+    }
+    let mut nodes = network.ghost_starts().collect_vec();
+    if nodes.is_empty() {
+        return Err(anyhow!("no ghost start nodes"));
+    }
+    for (steps, &instruction) in instructions.iter().cycle().enumerate() {
+        if nodes.iter().all(|&node| network.is_ghost_end(node)) {
+            return Ok(Some(steps));
+        }
+        if steps == max_steps {
+            return Ok(None);
+        }
+        for node in &mut nodes {
+            *node = network.next(*node, instruction)?;
+        }
+    }
+    unreachable!("the instructions cycle forever")
+}
+
+/// The steps at which a single ghost is on an end node. As soon as the ghost is on a node at
+/// the same instruction index for the second time, its walk repeats.
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct GhostCycle {
+    /// Steps before the cycle is entered.
+    tail_hits: Vec<usize>,
+    cycle_start: usize,
+    cycle_len: usize,
+    /// Steps of the first pass through the cycle.
+    cycle_hits: Vec<usize>,
+}
+
+impl GhostCycle {
+    fn new(network: &Network, instructions: &[Instruction], start_node: NodeId) -> Result<Self> {
+        let mut first_visits: HashMap<(NodeId, usize), usize> = HashMap::new();
+        let mut hits = Vec::new();
+        let mut node = start_node;
+        for step in 0.. {
+            let instruction_index = step % instructions.len();
+            if let Some(&cycle_start) = first_visits.get(&(node, instruction_index)) {
+                let (tail_hits, cycle_hits) = hits.into_iter().partition(|&hit| hit < cycle_start);
+                return Ok(Self {
+                    tail_hits,
+                    cycle_start,
+                    cycle_len: step - cycle_start,
+                    cycle_hits,
+                });
+            }
+            first_visits.insert((node, instruction_index), step);
+            if network.is_ghost_end(node) {
+                hits.push(step);
+            }
+            node = network.next(node, instructions[instruction_index])?;
+        }
+        unreachable!("the number of states is finite")
+    }
+
+    /// Whether the ghost is on an end node exactly every `cycle_len` steps, the assumption
+    /// which makes the LCM of the cycle lengths the answer.
+    fn is_periodic(&self) -> bool {
+        self.tail_hits.is_empty() && self.cycle_hits == [self.cycle_len]
+    }
+
+    fn steps_on_end_nodes(&self) -> Steps {
+        Steps {
+            finite: self.tail_hits.iter().copied().collect(),
+            progressions: self
+                .cycle_hits
+                .iter()
+                .map(|&start| Progression {
+                    start,
+                    period: self.cycle_len,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The steps `start`, `start + period`, `start + 2 * period`, ...
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct Progression {
+    start: usize,
+    period: usize,
+}
+
+impl Progression {
+    fn contains(&self, step: usize) -> bool {
+        step >= self.start && (step - self.start).is_multiple_of(self.period)
+    }
+
+    /// The steps in both progressions, using the generalized Chinese Remainder Theorem (the
+    /// periods don't have to be coprime). `None` if there are no such steps, fails if the
+    /// resulting progression doesn't fit into `usize`.
+    fn intersect(&self, other: &Self) -> Result<Option<Self>> {
+        let (a, n) = (self.start as i128, self.period as i128);
+        let (b, m) = (other.start as i128, other.period as i128);
+
+        // step = a + n * k with n * k = b - a (mod m), solvable iff gcd(n, m) divides b - a
+        let ExtendedGcd { gcd, x, .. } = n.extended_gcd(&m);
+        if (b - a) % gcd != 0 {
+            return Ok(None);
+        }
+        let m_reduced = m / gcd;
+        // both factors are below 2^64, so their product fits into u128 but not always into i128
+        let k = ((b - a) / gcd).rem_euclid(m_reduced) as u128 * x.rem_euclid(m_reduced) as u128
+            % m_reduced as u128;
+        let overflow = || anyhow!("the common steps of {self:?} and {other:?} overflow");
+        let period: usize = n
+            .checked_mul(m_reduced)
+            .and_then(|period| period.try_into().ok())
+            .ok_or_else(overflow)?;
+        let period = period as i128;
+
+        // the first solution which is part of both progressions, n * k < period fits
+        let min_start = a.max(b);
+        let start = min_start + (a + n * k as i128 - min_start).rem_euclid(period);
+        Ok(Some(Self {
+            start: start.try_into().map_err(|_| overflow())?,
+            period: period as usize,
+        }))
+    }
+}
+
+/// A set of steps made of finitely many single steps and progressions.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+struct Steps {
+    finite: BTreeSet<usize>,
+    progressions: Vec<Progression>,
+}
+
+impl Steps {
+    fn contains(&self, step: usize) -> bool {
+        self.finite.contains(&step) || self.progressions.iter().any(|p| p.contains(step))
+    }
+
+    fn intersect(&self, other: &Self) -> Result<Self> {
+        let finite = self
+            .finite
+            .iter()
+            .filter(|&&step| other.contains(step))
+            .chain(other.finite.iter().filter(|&&step| self.contains(step)))
+            .copied()
+            .collect();
+        let progressions = self
+            .progressions
+            .iter()
+            .cartesian_product(&other.progressions)
+            .filter_map(|(p1, p2)| p1.intersect(p2).transpose())
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sorted()
+            .dedup()
+            .collect();
+        Ok(Self {
+            finite,
+            progressions,
+        })
+    }
+
+    fn first(&self) -> Option<usize> {
+        let first_of_progressions = self.progressions.iter().map(|p| p.start).min();
+        self.finite
+            .first()
+            .copied()
+            .into_iter()
+            .chain(first_of_progressions)
+            .min()
+    }
+}
+
+/// Number of steps until all ghosts are on end nodes at the same time.
+fn ghost_steps(network: &Network, instructions: &[Instruction]) -> Result<usize> {
+    if instructions.is_empty() {
+        return Err(anyhow!("no instructions"));
+    }
+    let cycles = network
+        .ghost_starts()
+        .map(|start_node| GhostCycle::new(network, instructions, start_node))
+        .collect::<Result<Vec<_>>>()?;
+    if cycles.is_empty() {
+        return Err(anyhow!("no ghost start nodes"));
+    }
+
+    if cycles.iter().all(GhostCycle::is_periodic) {
+        return cycles.iter().try_fold(1, |lcm: usize, cycle| {
+            (lcm / lcm.gcd(&cycle.cycle_len))
+                .checked_mul(cycle.cycle_len)
+                .ok_or_else(|| anyhow!("the common steps of the ghost cycles overflow"))
+        });
+    }
+
+    let mut steps_on_end_nodes = cycles.iter().map(GhostCycle::steps_on_end_nodes);
+    let first = steps_on_end_nodes.next().unwrap();
+    steps_on_end_nodes
+        .try_fold(first, |steps, ghost_steps| steps.intersect(&ghost_steps))?
+        .first()
+        .ok_or(anyhow!(
+            "the ghosts are never on end nodes at the same time"
+        ))
+}
+
+pub struct Puzzle {
+    instructions: Vec<Instruction>,
+    network: Network,
+}
+
+/// Parses the instructions and the network.
+fn parse(input: &Input) -> Result<(Vec<Instruction>, Network)> {
+    let mut lines = input.located_lines();
+    let line = lines.next().ok_or(anyhow!("no instructions"))?;
+    if line.text().is_empty() {
+        return Err(line.error(line.text(), "no instructions").into());
+    }
+    let instructions = line.parse_chars()?;
+    let network = Network::parse(lines)?;
+    Ok((instructions, network))
+}
+
+impl Solution for Puzzle {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        let (instructions, network) = parse(input)?;
+        Ok(Self {
+            instructions,
+            network,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let node = |name| self.network.id(name).ok_or(anyhow!("no node {name}"));
+        let path = walk_until_end(
+            &self.network,
+            &self.instructions,
+            node("AAA")?,
+            node("ZZZ")?,
+        )?;
+        Ok(path.len() - 1)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        ghost_steps(&self.network, &self.instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ParseError;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn parse_text(text: &str) -> (Vec<Instruction>, Network) {
+        parse(&Input::new("day8.txt", text)).unwrap()
+    }
+
+    #[rstest]
+    #[case("input/day8_example1.txt", 2)]
+    #[case("input/day8_example2.txt", 6)]
+    fn example(#[case] path: &str, #[case] steps: usize) {
+        let input = Input::read(path).unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.part1().unwrap(), steps);
+    }
+
+    #[test]
+    fn ghost_example() {
+        let input = Input::read("input/day8_example3.txt").unwrap();
+        let puzzle = Puzzle::parse(&input).unwrap();
+        assert_eq!(puzzle.part1().unwrap_err().to_string(), "no node AAA");
+        assert_eq!(puzzle.part2().unwrap(), 6);
+        let answers = crate::DAYS[7].solve(&input).unwrap();
+        assert!(answers.part1.is_err());
+        assert_eq!(answers.part2.unwrap(), "6");
+
+        let (instructions, network) = parse(&input).unwrap();
+        assert_eq!(ghost_steps(&network, &instructions).unwrap(), 6);
+        assert_eq!(
+            ghost_walk_until_end(&network, &instructions, 100).unwrap(),
+            Some(6)
+        );
+    }
+
+    const IRREGULAR_GHOSTS: &str = "LR
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)
+33A = (33Z, 33Z)
+33Z = (33B, 33B)
+33B = (33B, 33B)";
+
+    #[test]
+    fn ghost_cycles() {
+        let (instructions, network) = parse_text(IRREGULAR_GHOSTS);
+        // 11Z is hit every 3 steps, but at alternating instruction indices
+        assert_eq!(
+            GhostCycle::new(&network, &instructions, network.id("11A").unwrap()).unwrap(),
+            GhostCycle {
+                tail_hits: vec![],
+                cycle_start: 1,
+                cycle_len: 6,
+                cycle_hits: vec![1, 4],
+            }
+        );
+        assert_eq!(
+            GhostCycle::new(&network, &instructions, network.id("33A").unwrap()).unwrap(),
+            GhostCycle {
+                tail_hits: vec![1],
+                cycle_start: 2,
+                cycle_len: 2,
+                cycle_hits: vec![],
+            }
+        );
+        // 22Z is hit at steps 2, 4, 6, ...
+        assert!(
+            GhostCycle::new(&network, &instructions, network.id("22A").unwrap())
+                .unwrap()
+                .is_periodic()
+        );
+        assert!(
+            !GhostCycle::new(&network, &instructions, network.id("11A").unwrap())
+                .unwrap()
+                .is_periodic()
+        );
+    }
+
+    #[rstest]
+    #[case(&["11", "22"], Some(4))]
+    #[case(&["11", "33"], Some(1))]
+    #[case(&["22", "33"], None)]
+    #[case(&["11", "22", "33"], None)]
+    fn irregular_ghosts(#[case] ghosts: &[&str], #[case] expected: Option<usize>) {
+        let text = IRREGULAR_GHOSTS
+            .lines()
+            .filter(|line| !line.contains('=') || ghosts.iter().any(|g| line.starts_with(g)))
+            .join("\n");
+        let (instructions, network) = parse_text(&text);
+        let steps = ghost_steps(&network, &instructions).ok();
+        assert_eq!(steps, expected);
+        assert_eq!(
+            ghost_walk_until_end(&network, &instructions, 1000).unwrap(),
+            steps
+        );
+    }
+
+    #[rstest]
+    #[case((2, 3), (3, 5), Some((8, 15)))]
+    #[case((0, 4), (2, 6), Some((8, 12)))]
+    #[case((1, 4), (2, 6), None)]
+    #[case((10, 1), (3, 7), Some((10, 7)))]
+    #[case((4, 6), (4, 6), Some((4, 6)))]
+    fn progression_intersection(
+        #[case] first: (usize, usize),
+        #[case] second: (usize, usize),
+        #[case] expected: Option<(usize, usize)>,
+    ) {
+        let progression = |(start, period)| Progression { start, period };
+        let intersection = progression(first).intersect(&progression(second)).unwrap();
+        assert_eq!(intersection, expected.map(progression));
+        if let Some(intersection) = intersection {
+            for step in 0..100 {
+                assert_eq!(
+                    intersection.contains(step),
+                    progression(first).contains(step) && progression(second).contains(step)
+                );
+            }
+        }
+    }
+
+    /// Ghosts `<i>A` which are on their end node `<i>Z` exactly every `len` steps, with all other
+    /// nodes named so that they are neither start nor end nodes.
+    fn periodic_ghosts(cycle_lens: &[usize]) -> String {
+        const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let last_digits = DIGITS
+            .iter()
+            .filter(|&&d| d != b'A' && d != b'Z')
+            .collect_vec();
+        let mut names = (0..).map(|i: usize| {
+            let last = *last_digits[i % last_digits.len()];
+            let i = i / last_digits.len();
+            String::from_utf8(vec![DIGITS[i / 36], DIGITS[i % 36], last]).unwrap()
+        });
+
+        let mut lines = vec!["L".to_owned(), String::new()];
+        for (ghost, &len) in cycle_lens.iter().enumerate() {
+            let prefix = format!(
+                "{}{}",
+                DIGITS[ghost / 36] as char,
+                DIGITS[ghost % 36] as char
+            );
+            // start -> cycle[0] -> ... -> cycle[len - 2] -> end -> cycle[0]
+            let cycle = names.by_ref().take(len - 1).collect_vec();
+            let end = format!("{prefix}Z");
+            let mut walk = vec![format!("{prefix}A")];
+            walk.extend(cycle.iter().cloned());
+            walk.push(end.clone());
+            walk.push(cycle[0].clone());
+            for (node, next) in walk.iter().tuple_windows() {
+                lines.push(format!("{node} = ({next}, {next})"));
+            }
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn periodic_ghosts_overflow() {
+        let (instructions, network) = parse_text(&periodic_ghosts(&[5, 7, 3]));
+        assert_eq!(ghost_steps(&network, &instructions).unwrap(), 105);
+        assert_eq!(
+            ghost_walk_until_end(&network, &instructions, 1000).unwrap(),
+            Some(105)
+        );
+
+        // the product of these coprime cycle lengths is about 5.08e19 > usize::MAX
+        let cycle_lens = [6997, 7001, 7013, 7019, 7027, 3];
+        let (instructions, network) = parse_text(&periodic_ghosts(&cycle_lens));
+        let err = ghost_steps(&network, &instructions).unwrap_err();
+        assert!(err.to_string().contains("overflow"), "{err}");
+    }
+
+    #[test]
+    fn progression_intersection_overflow() {
+        // 2^40 is a common step, but the combined period 2^40 * (2^40 - 1) is too large
+        let first = Progression {
+            start: 0,
+            period: 1 << 40,
+        };
+        let second = Progression {
+            start: 1 << 40,
+            period: (1 << 40) - 1,
+        };
+        let err = first.intersect(&second).unwrap_err();
+        assert!(err.to_string().contains("overflow"), "{err}");
+
+        // the product of the reduced factors doesn't fit into i128
+        let first = Progression {
+            start: 1,
+            period: usize::MAX,
+        };
+        let second = Progression {
+            start: 0,
+            period: usize::MAX - 1,
+        };
+        assert!(first.intersect(&second).is_err());
+    }
+
+    #[test]
+    fn parse_network() {
+        let (instructions, network) = parse_text("RL\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)");
+        assert_eq!(instructions, vec![Instruction::Right, Instruction::Left]);
+        let aaa = network.id("AAA").unwrap();
+        let ccc = network.id("CCC").unwrap();
+        assert_eq!(network.name(aaa), "AAA");
+        assert_eq!(network.next(aaa, Instruction::Right).unwrap(), ccc);
+        assert_eq!(network.nodes().count(), 3);
+
+        let err = parse(&Input::new("day8.txt", "LRX\n")).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+
+    #[rstest]
+    #[case("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ", 4)]
+    #[case("L\n\nAAA = (ZZZ, ZZZ)\n ZZZ = (ZZZ, ZZZ)", 4)]
+    #[case("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ) x\n", 4)]
+    #[case("L\n\nAAA = (zzz, ZZZ)", 3)]
+    fn invalid_network(#[case] text: &str, #[case] line: usize) {
+        let err = parse(&Input::new("day8.txt", text)).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(err.line, line);
+        assert!(
+            err.message
+                .contains("expected '<node> = (<left>, <right>)'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn node_names_with_zeros() {
+        let (instructions, network) = parse_text(
+            "L\n\n11A = (10Z, 10Z)\n10Z = (10Z, 10Z)\n0QA = (0QZ, 0QZ)\n0QZ = (0QA, 0QA)",
+        );
+        assert_eq!(network.nodes().count(), 4);
+        assert_eq!(ghost_steps(&network, &instructions).unwrap(), 1);
+    }
+
+    #[test]
+    fn no_ghosts() {
+        let (instructions, network) = parse_text("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        let walk_err = ghost_walk_until_end(&network, &instructions, 100).unwrap_err();
+        let err = ghost_steps(&network, &instructions).unwrap_err();
+        assert_eq!(walk_err.to_string(), "no ghost start nodes");
+        assert_eq!(err.to_string(), "no ghost start nodes");
+    }
+
+    #[test]
+    fn empty_instructions() {
+        let input = Input::new("day8.txt", "\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        let err = Puzzle::parse(&input).err().unwrap();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(err.line, 1);
+        assert!(err.message.contains("no instructions"), "{err}");
+    }
+
+    #[rstest]
+    #[case(
+        "L",
+        "AAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)",
+        "target unreachable"
+    )]
+    #[case(
+        "LR",
+        "AAA = (AAA, BBB)\nBBB = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)",
+        "target unreachable"
+    )]
+    #[case("", "AAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)", "target unreachable")]
+    #[case(
+        "L",
+        "AAA = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)",
+        "dangling node BBB referenced by AAA"
+    )]
+    fn walk_errors(#[case] instructions: &str, #[case] network: &str, #[case] expected: &str) {
+        // built separately as parsing rejects empty instructions
+        let instructions = instructions
+            .chars()
+            .map(|c| Instruction::try_from(c).unwrap())
+            .collect_vec();
+        let network = Network::parse(Input::new("day8.txt", network).located_lines()).unwrap();
+        let node = |name| network.id(name).unwrap();
+        let err = walk_until_end(&network, &instructions, node("AAA"), node("ZZZ")).unwrap_err();
+        assert!(err.to_string().starts_with(expected), "{err}");
+    }
+
+    #[test]
+    fn walk_to_undefined_end() {
+        // the end node is never left, so it doesn't need to be defined
+        let (instructions, network) = parse_text("L\n\nAAA = (ZZZ, ZZZ)");
+        let node = |name| network.id(name).unwrap();
+        let path = walk_until_end(&network, &instructions, node("AAA"), node("ZZZ")).unwrap();
+        assert_eq!(path, vec![node("AAA"), node("ZZZ")]);
+    }
+
+    /// A network of nodes `11?`, `22?`, ... from `(left, right, kind)` with `kind` being the
+    /// last letter of the name.
+    fn network_text(instructions: &[bool], nodes: &[(usize, usize, char)]) -> String {
+        let name = |i: usize| format!("{0}{0}{1}", i + 1, nodes[i].2);
+        let instructions: String = instructions
+            .iter()
+            .map(|&left| if left { 'L' } else { 'R' })
+            .collect();
+        let lines = nodes.iter().enumerate().map(|(i, &(left, right, _))| {
+            format!("{} = ({}, {})", name(i), name(left), name(right))
+        });
+        format!("{instructions}\n\n{}", lines.collect_vec().join("\n"))
+    }
+
+    fn network_strategy() -> impl Strategy<Value = (Vec<bool>, Vec<(usize, usize, char)>)> {
+        let instructions = prop::collection::vec(any::<bool>(), 1..3);
+        let nodes = (1..7usize).prop_flat_map(|n| {
+            prop::collection::vec((0..n, 0..n, prop::sample::select(vec!['A', 'B', 'Z'])), n)
+        });
+        (instructions, nodes)
+    }
+
+    proptest! {
+        #[test]
+        fn ghost_steps_matches_naive_walk((instructions, nodes) in network_strategy()) {
+            let (instructions, network) = parse_text(&network_text(&instructions, &nodes));
+
+            // every ghost repeats after at most 6 nodes * 2 instructions steps, so if they meet
+            // at all they do so within the LCM of all possible cycle lengths
+            let max_steps = (1..=12).fold(1, num::integer::lcm) + 12;
+            let steps = ghost_steps(&network, &instructions);
+            match ghost_walk_until_end(&network, &instructions, max_steps) {
+                Ok(walked) => prop_assert_eq!(steps.ok(), walked),
+                Err(err) => prop_assert_eq!(steps.unwrap_err().to_string(), err.to_string()),
+            }
+        }
+    }
+}
@@ -1,17 +1,17 @@
+use crate::{Input, ParseError, Solution};
 use anyhow::Result;
-use aoc23::read_lines;
 use itertools::Itertools;
 
 fn pairwise_diff(values: &[isize]) -> Vec<isize> {
     values
-        .into_iter()
+        .iter()
         .tuple_windows()
         .map(|(x, y)| y - x)
         .collect_vec()
 }
 
 fn extrapolate(values: &[isize]) -> (isize, isize) {
-    if values.into_iter().all(|&v| v == 0) {
+    if values.iter().all(|&v| v == 0) {
         (0, 0)
     } else {
         let diff = pairwise_diff(values);
@@ -22,24 +22,36 @@ fn extrapolate(values: &[isize]) -> (isize, isize) {
     }
 }
 
-fn main() -> Result<()> {
-    let lines = read_lines("input/day9.txt")?;
+pub struct Puzzle {
+    extrapolations: Vec<(isize, isize)>,
+}
 
-    let (sum_prev, sum_next) = lines
-        .iter()
-        .map(|s| {
-            let values = s
-                .split_whitespace()
-                .map(|s| s.parse().unwrap())
-                .collect_vec();
-            extrapolate(&values)
-        })
-        .reduce(|(sum_prev, sum_next), (prev, next)| (sum_prev + prev, sum_next + next))
-        .unwrap();
-    println!("Part I: {sum_next}");
-    println!("Part II: {sum_prev}");
-
-    Ok(())
+impl Solution for Puzzle {
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &Input) -> Result<Self> {
+        let extrapolations = input
+            .located_lines()
+            .map(|line| {
+                let values = line
+                    .text()
+                    .split_whitespace()
+                    .map(|s| line.parse(s))
+                    .collect::<Result<Vec<_>, ParseError>>()?;
+                Ok(extrapolate(&values))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { extrapolations })
+    }
+
+    fn part1(&self) -> Result<isize> {
+        Ok(self.extrapolations.iter().map(|(_, next)| next).sum())
+    }
+
+    fn part2(&self) -> Result<isize> {
+        Ok(self.extrapolations.iter().map(|(prev, _)| prev).sum())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,270 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// A point in screen coordinates, `x` grows to the east and `y` grows to the south.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point on a grid, which can't leave the first quadrant.
+pub type Position = Point<usize>;
+
+/// A point anywhere on the plane, also used as the difference of two points.
+pub type Offset = Point<isize>;
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// Only offsets can be added and subtracted freely, positions move with `checked_add` or `step`
+// so that they can't underflow.
+impl Add for Offset {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Offset {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Point<usize> {
+    /// Moves by `offset`, `None` if that would leave the first quadrant or overflow.
+    pub fn checked_add(self, offset: Offset) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+
+    /// The adjacent point in `direction`, `None` if that would leave the first quadrant.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.checked_add(direction.offset())
+    }
+
+    pub fn to_signed(self) -> Option<Offset> {
+        Some(Offset::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+        ))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Point<isize> {
+    /// Moves by `offset`, `None` on overflow.
+    pub fn checked_add(self, offset: Offset) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(offset.x)?,
+            self.y.checked_add(offset.y)?,
+        ))
+    }
+
+    /// The adjacent point in `direction`, `None` on overflow.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.checked_add(direction.offset())
+    }
+
+    pub fn to_unsigned(self) -> Option<Position> {
+        Some(Position::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+        ))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Rotates by 90 degrees clockwise around the origin, `None` on overflow.
+    pub fn rotate_right(self) -> Option<Self> {
+        Some(Self::new(self.y.checked_neg()?, self.x))
+    }
+
+    /// Rotates by 90 degrees counterclockwise around the origin, `None` on overflow.
+    pub fn rotate_left(self) -> Option<Self> {
+        Some(Self::new(self.y, self.x.checked_neg()?))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// North, east, south and west.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All directions in clockwise order, starting with north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub const fn offset(self) -> Offset {
+        match self {
+            Direction::North => Offset::new(0, -1),
+            Direction::NorthEast => Offset::new(1, -1),
+            Direction::East => Offset::new(1, 0),
+            Direction::SouthEast => Offset::new(1, 1),
+            Direction::South => Offset::new(0, 1),
+            Direction::SouthWest => Offset::new(-1, 1),
+            Direction::West => Offset::new(-1, 0),
+            Direction::NorthWest => Offset::new(-1, -1),
+        }
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        Self::CARDINAL.contains(&self)
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn.
+    pub fn rotate(self, eighths: isize) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap() as isize;
+        Self::ALL[(index + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Position::new(0, 0), Direction::North, None)]
+    #[case(Position::new(0, 0), Direction::West, None)]
+    #[case(Position::new(0, 0), Direction::SouthEast, Some(Position::new(1, 1)))]
+    #[case(Position::new(3, 0), Direction::SouthWest, Some(Position::new(2, 1)))]
+    #[case(Position::new(usize::MAX, 0), Direction::East, None)]
+    fn step_position(
+        #[case] pos: Position,
+        #[case] direction: Direction,
+        #[case] expected: Option<Position>,
+    ) {
+        assert_eq!(pos.step(direction), expected);
+    }
+
+    #[test]
+    fn step_offset() {
+        assert_eq!(
+            Offset::new(0, 0).step(Direction::NorthWest),
+            Some(Offset::new(-1, -1))
+        );
+        assert_eq!(Offset::new(isize::MIN, 0).step(Direction::West), None);
+    }
+
+    #[test]
+    fn offset_arithmetic() {
+        let offset = Offset::new(1, -2) + Offset::new(-3, 5) - Offset::new(1, 1);
+        assert_eq!(offset, Offset::new(-3, 2));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Position::new(1, 6);
+        let b = Position::new(5, 11);
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(a.chebyshev_distance(b), 5);
+        assert_eq!(b.manhattan_distance(a), 9);
+
+        let a = Offset::new(-3, 2);
+        let b = Offset::new(4, -1);
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 7);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Position::new(2, 3).to_signed(), Some(Offset::new(2, 3)));
+        assert_eq!(Position::new(usize::MAX, 3).to_signed(), None);
+        assert_eq!(Offset::new(-1, 3).to_unsigned(), None);
+        assert_eq!(Offset::new(2, 3).to_unsigned(), Some(Position::new(2, 3)));
+    }
+
+    #[test]
+    fn rotate_point() {
+        let north = Direction::North.offset();
+        assert_eq!(north.rotate_right(), Some(Direction::East.offset()));
+        assert_eq!(north.rotate_left(), Some(Direction::West.offset()));
+        assert_eq!(
+            Offset::new(3, 1)
+                .rotate_right()
+                .and_then(Offset::rotate_left),
+            Some(Offset::new(3, 1))
+        );
+        assert_eq!(Offset::new(0, isize::MIN).rotate_right(), None);
+    }
+
+    #[test]
+    fn rotate_direction() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthWest.rotate(1), Direction::North);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_right(),
+                Some(direction.turn_right().offset())
+            );
+        }
+    }
+}
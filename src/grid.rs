@@ -0,0 +1,337 @@
+use crate::geom::{Direction, Position};
+use crate::{Input, ParseError};
+use anyhow::{anyhow, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, with position `(0, 0)` being the top left corner.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(anyhow!(
+                "row {y} has {} cells but expected {width}",
+                row.len()
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses every character of the input into a cell, all lines have to be of equal length.
+    pub fn parse(input: &Input) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+        T::Error: fmt::Display,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.located_lines() {
+            let row = line.parse_chars()?;
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    return Err(line.error(
+                        line.text(),
+                        format!("expected {width} cells but found {}", row.len()),
+                    ));
+                }
+                Some(_) => {}
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[self.index_of(pos)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            let index = self.index_of(pos);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    fn index_of(&self, pos: Position) -> usize {
+        pos.y * self.width + pos.x
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    /// All cells together with their position in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell (in row-major order) matching `predicate`.
+    pub fn position<P>(&self, mut predicate: P) -> Option<Position>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The horizontally and vertically adjacent positions which are within the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(pos, &Direction::CARDINAL)
+    }
+
+    /// The horizontally, vertically and diagonally adjacent positions which are within the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(pos, &Direction::ALL)
+    }
+
+    fn neighbours(
+        &self,
+        pos: Position,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Position> + '_ {
+        directions
+            .iter()
+            .filter_map(move |&direction| pos.step(direction))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Surrounds the grid with `padding` cells of `value` on every side.
+    pub fn padded(&self, padding: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width + 2 * padding;
+        let height = self.height + 2 * padding;
+        let mut padded = Self::new(width, height, value);
+        for (pos, cell) in self.iter() {
+            padded[Position::new(pos.x + padding, pos.y + padding)] = cell.clone();
+        }
+        padded
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos} out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use itertools::Itertools;
+
+    fn example_grid() -> Grid<u32> {
+        // 1 2 3
+        // 4 5 6
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn parse_grid() {
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        struct Digit(u32);
+
+        impl TryFrom<char> for Digit {
+            type Error = String;
+
+            fn try_from(c: char) -> Result<Self, Self::Error> {
+                c.to_digit(10)
+                    .map(Digit)
+                    .ok_or(format!("{c} is not a digit"))
+            }
+        }
+
+        let grid: Grid<Digit> = Grid::parse(&Input::new("grid.txt", "123\n456")).unwrap();
+        assert_eq!(grid.map(|d| d.0), example_grid());
+
+        let err = Grid::<Digit>::parse(&Input::new("grid.txt", "123\n4x6")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = Grid::<Digit>::parse(&Input::new("grid.txt", "123\n45")).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "expected 3 cells but found 2");
+    }
+
+    #[test]
+    fn from_rows_of_unequal_length() {
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn bounds_checked_access() {
+        let grid = example_grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Position::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.get(Position::new(0, 2)), None);
+        assert_eq!(grid[Position::new(1, 0)], 2);
+        assert_eq!(grid.position(|&n| n == 5), Some(Position::new(1, 1)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = example_grid();
+        assert_eq!(
+            grid.neighbours4(Position::new(0, 0)).collect_vec(),
+            vec![Position::new(1, 0), Position::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4(Position::new(1, 1)).collect_vec(),
+            vec![
+                Position::new(1, 0),
+                Position::new(2, 1),
+                Position::new(0, 1)
+            ]
+        );
+        assert_eq!(
+            grid.neighbours8(Position::new(0, 0)).collect_vec(),
+            vec![
+                Position::new(1, 0),
+                Position::new(1, 1),
+                Position::new(0, 1)
+            ]
+        );
+        assert_eq!(grid.neighbours8(Position::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = example_grid();
+        assert_eq!(grid.rows().collect_vec(), vec![&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.copied().collect_vec())
+                .collect_vec(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let transposed = example_grid().transpose();
+        assert_eq!(
+            transposed,
+            Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap()
+        );
+        assert_eq!(transposed.transpose(), example_grid());
+    }
+
+    #[test]
+    fn padded() {
+        let padded = example_grid().padded(1, 0);
+        assert_eq!(
+            padded,
+            Grid::from_rows(vec![
+                vec![0, 0, 0, 0, 0],
+                vec![0, 1, 2, 3, 0],
+                vec![0, 4, 5, 6, 0],
+                vec![0, 0, 0, 0, 0],
+            ])
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Puzzle input which remembers where it came from, so that parse errors can point at the
+/// offending text.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Input {
+    name: String,
+    lines: Vec<String>,
+}
+
+impl Input {
+    pub fn new(name: impl Into<String>, text: &str) -> Self {
+        Self {
+            name: name.into(),
+            lines: text.lines().map(|s| s.to_owned()).collect(),
+        }
+    }
+
+    pub fn read<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        Ok(Self::new(
+            path.display().to_string(),
+            &fs::read_to_string(path)?,
+        ))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Iterates over the lines together with their location.
+    pub fn located_lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.lines.iter().enumerate().map(|(i, text)| Line {
+            file: &self.name,
+            number: i + 1,
+            text,
+        })
+    }
+
+    /// Parses every line as a whole into a `T`.
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.located_lines()
+            .map(|line| line.parse(line.text()))
+            .collect()
+    }
+}
+
+/// A single line of an [`Input`].
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    file: &'a str,
+    number: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based line number.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// 1-based column (in characters) of `part`, which has to be a slice of this line's text.
+    /// Falls back to the first column if it isn't.
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let end = start + self.text.len();
+        let part_start = part.as_ptr() as usize;
+        if (start..=end).contains(&part_start) {
+            self.text[..part_start - start].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// Creates an error located at `part`, a slice of this line's text.
+    pub fn error(&self, part: &str, message: impl fmt::Display) -> ParseError {
+        ParseError {
+            file: self.file.to_owned(),
+            line: self.number,
+            column: self.column_of(part),
+            text: part.to_owned(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Parses `part`, a slice of this line's text, into a `T`.
+    pub fn parse<T>(&self, part: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.parse().map_err(|err| self.error(part, err))
+    }
+
+    /// Converts every character of the line into a `T`.
+    pub fn parse_chars<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: TryFrom<char>,
+        T::Error: fmt::Display,
+    {
+        self.text
+            .char_indices()
+            .map(|(i, c)| {
+                T::try_from(c).map_err(|err| self.error(&self.text[i..i + c.len_utf8()], err))
+            })
+            .collect()
+    }
+
+    /// Splits the whole line at the first occurrence of `delimiter`.
+    pub fn split_once(&self, delimiter: char) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(self.text, format!("expected '{delimiter}'")))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} (at `{}`)",
+            self.file, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Digit;
+
+    impl TryFrom<char> for Digit {
+        type Error = &'static str;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            if c.is_ascii_digit() {
+                Ok(Digit)
+            } else {
+                Err("not a digit")
+            }
+        }
+    }
+
+    #[test]
+    fn parse_lines() {
+        let input = Input::new("numbers.txt", "1\n22\n333");
+        assert_eq!(input.parse_lines::<u32>().unwrap(), vec![1, 22, 333]);
+    }
+
+    #[test]
+    fn parse_lines_error_location() {
+        let input = Input::new("numbers.txt", "1\nx2\n333");
+        let err = input.parse_lines::<u32>().unwrap_err();
+        assert_eq!(err.file, "numbers.txt");
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 1);
+        assert_eq!(err.text, "x2");
+    }
+
+    #[test]
+    fn parse_part_error_location() {
+        let input = Input::new("cards.txt", "Card 1: 41 4x | 83");
+        let line = input.located_lines().next().unwrap();
+        let (_, numbers) = line.split_once(':').unwrap();
+        let err = numbers
+            .split_whitespace()
+            .map(|s| line.parse::<u32>(s))
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(err.line, 1);
+        assert_eq!(err.column, 12);
+        assert_eq!(err.text, "4x");
+        assert_eq!(
+            err.to_string(),
+            "cards.txt:1:12: invalid digit found in string (at `4x`)"
+        );
+    }
+
+    #[test]
+    fn parse_chars_error_location() {
+        let input = Input::new("digits.txt", "123\n4a6");
+        let line = input.located_lines().nth(1).unwrap();
+        let err = line.parse_chars::<Digit>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "a");
+        assert_eq!(err.message, "not a digit");
+    }
+
+    #[test]
+    fn split_once_error() {
+        let input = Input::new("games.txt", "Game 1");
+        let line = input.located_lines().next().unwrap();
+        let err = line.split_once(':').unwrap_err();
+        assert_eq!(err.column, 1);
+        assert_eq!(err.text, "Game 1");
+    }
+}
@@ -1,13 +1,35 @@
-use std::fs;
-use std::io;
-use std::path::Path;
+mod answers;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod geom;
+mod grid;
+mod input;
+mod solution;
 
-pub fn read_lines<P>(filename: P) -> io::Result<Vec<String>>
-where
-    P: AsRef<Path>,
-{
-    Ok(fs::read_to_string(filename)?
-        .lines()
-        .map(|s| s.to_owned())
-        .collect())
-}
+pub use answers::{ExpectedAnswers, Verdict};
+pub use grid::Grid;
+pub use input::{Input, Line, ParseError};
+pub use solution::{Answers, Day, Solution};
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Puzzle>(1),
+    Day::new::<day2::Puzzle>(2),
+    Day::new::<day3::Puzzle>(3),
+    Day::new::<day4::Puzzle>(4),
+    Day::new::<day5::Puzzle>(5),
+    Day::new::<day6::Puzzle>(6),
+    Day::new::<day7::Puzzle>(7),
+    Day::new::<day8::Puzzle>(8),
+    Day::new::<day9::Puzzle>(9),
+    Day::new::<day10::Puzzle>(10),
+    Day::new::<day11::Puzzle>(11),
+];
@@ -0,0 +1,113 @@
+use anyhow::{anyhow, Result};
+use aoc23::{Verdict, DAYS};
+use std::ops::RangeInclusive;
+use std::process::ExitCode;
+
+/// Parses a day selection, either a single day (`5`) or an inclusive range (`3-7`).
+fn parse_selection(s: &str) -> Result<RangeInclusive<u32>> {
+    match s.split_once('-') {
+        Some((first, last)) => {
+            let first = first.trim().parse()?;
+            let last = last.trim().parse()?;
+            if first > last {
+                return Err(anyhow!("invalid day range {s}"));
+            }
+            Ok(first..=last)
+        }
+        None => {
+            let day = s.trim().parse()?;
+            Ok(day..=day)
+        }
+    }
+}
+
+/// Prints the answer to one part and its verdict, returns whether the part succeeded.
+fn report(part: &str, answer: &Result<String>, expected: Option<&str>) -> bool {
+    match answer {
+        Ok(answer) => {
+            let verdict = Verdict::new(answer, expected);
+            println!("  {part}: {answer} ({verdict})");
+            !verdict.is_fail()
+        }
+        Err(err) => {
+            println!("  {part}: error: {err:#}");
+            false
+        }
+    }
+}
+
+fn main() -> Result<ExitCode> {
+    let selections = std::env::args()
+        .skip(1)
+        .map(|s| parse_selection(&s))
+        .collect::<Result<Vec<_>>>()?;
+
+    let days = DAYS
+        .iter()
+        .filter(|day| selections.is_empty() || selections.iter().any(|s| s.contains(&day.number)))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(anyhow!("no registered day matches the selection"));
+    }
+
+    let mut failed = false;
+    for day in days {
+        println!("Day {}", day.number);
+        match day
+            .run()
+            .and_then(|answers| Ok((answers, day.expected_answers()?)))
+        {
+            Ok((answers, expected)) => {
+                failed |= !report("Part I", &answers.part1, expected.part1.as_deref());
+                failed |= !report("Part II", &answers.part2, expected.part2.as_deref());
+            }
+            Err(err) => {
+                println!("  Error: {err:#}");
+                failed = true;
+            }
+        }
+    }
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("5", 5..=5)]
+    #[case("3-7", 3..=7)]
+    #[case("10-10", 10..=10)]
+    fn parse_valid_selection(#[case] input: &str, #[case] expected: RangeInclusive<u32>) {
+        assert_eq!(parse_selection(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("x")]
+    #[case("7-3")]
+    fn parse_invalid_selection(#[case] input: &str) {
+        assert!(parse_selection(input).is_err());
+    }
+
+    #[rstest]
+    #[case(Ok("6".to_owned()), None, true)]
+    #[case(Ok("6".to_owned()), Some("6"), true)]
+    #[case(Ok("6".to_owned()), Some("7"), false)]
+    #[case(Err(anyhow!("no node AAA")), None, false)]
+    #[case(Err(anyhow!("no node AAA")), Some("6"), false)]
+    fn failed_parts_fail_the_run(
+        #[case] answer: Result<String>,
+        #[case] expected: Option<&str>,
+        #[case] succeeded: bool,
+    ) {
+        assert_eq!(report("Part I", &answer, expected), succeeded);
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::{Context, Result};
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use crate::{ExpectedAnswers, Input};
+
+/// A puzzle solution: the input is parsed once and both parts are answered from it. Either
+/// part may fail without failing the other one.
+pub trait Solution: Sized {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &Input) -> Result<Self>;
+
+    fn part1(&self) -> Result<Self::Part1>;
+
+    fn part2(&self) -> Result<Self::Part2>;
+}
+
+#[derive(Debug)]
+pub struct Answers {
+    pub part1: Result<String>,
+    pub part2: Result<String>,
+}
+
+/// A registered day, solvable without knowing its concrete `Solution` type.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    solve: fn(&Input) -> Result<Answers>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u32) -> Self {
+        Self {
+            number,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(format!("input/day{}.txt", self.number))
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        PathBuf::from(format!("answers/day{}.toml", self.number))
+    }
+
+    pub fn expected_answers(&self) -> Result<ExpectedAnswers> {
+        ExpectedAnswers::load(self.answers_path())
+    }
+
+    pub fn solve(&self, input: &Input) -> Result<Answers> {
+        (self.solve)(input)
+    }
+
+    pub fn run(&self) -> Result<Answers> {
+        let path = self.input_path();
+        let input =
+            Input::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+        self.solve(&input)
+    }
+}
+
+fn solve<S: Solution>(input: &Input) -> Result<Answers> {
+    let solution = S::parse(input)?;
+    Ok(Answers {
+        part1: solution.part1().map(|answer| answer.to_string()),
+        part2: solution.part2().map(|answer| answer.to_string()),
+    })
+}